@@ -0,0 +1,169 @@
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::bench::{self, BenchResult, Stage};
+use aoc_common::input::read_file;
+use aoc_common::json::{self, Value};
+use aoc_common::report;
+use aoc_common::timing::Stats;
+use aoc_common::{Error, ParseError, Result};
+
+use crate::cli::BenchArgs;
+use crate::days;
+
+// JSON Lines at the workspace root, one record per benchmarked stage
+pub const HISTORY_FILE: &str = "bench_history.jsonl";
+
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    // Seconds since the Unix epoch
+    pub time: u64,
+    pub day: u32,
+    pub stage: Stage,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn to_json(&self) -> String {
+        json::object(&[
+            ("time", self.time.to_string()),
+            ("day", self.day.to_string()),
+            ("stage", json::quote(&self.stage.to_string())),
+            ("runs", self.runs.to_string()),
+            ("median_ns", self.stats.median.as_nanos().to_string()),
+            ("min_ns", self.stats.min.as_nanos().to_string()),
+            ("max_ns", self.stats.max.as_nanos().to_string()),
+        ])
+    }
+
+    fn from_json(line: &str) -> Result<Record> {
+        let fields = json::parse_object(line)?;
+        let number = |key: &str| {
+            json::get(&fields, key).and_then(Value::as_u64).ok_or_else(|| ParseError::new(line, format!("expected a number for '{}'", key)))
+        };
+        let nanos = |key: &str| number(key).map(Duration::from_nanos);
+        let stage = json::get(&fields, "stage").and_then(Value::as_str).and_then(Stage::from_name);
+
+        Ok(Record {
+            time: number("time")?,
+            day: number("day")? as u32,
+            stage: stage.ok_or_else(|| ParseError::new(line, "expected a stage like 'parse' or 'part1'"))?,
+            runs: number("runs")? as usize,
+            stats: Stats { median: nanos("median_ns")?, min: nanos("min_ns")?, max: nanos("max_ns")? },
+        })
+    }
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<Record>> {
+    let text = match read_file(path) {
+        Ok(text) => text,
+        Err(Error::Io { source, .. }) if source.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+    text.lines().filter(|line| !line.trim().is_empty()).map(Record::from_json).collect::<Result<_>>().map_err(|error| match error {
+        Error::Parse(error) => Error::Usage(format!("{}: {}", path.display(), error.locate(&text))),
+        error => error,
+    })
+}
+
+fn append_history(path: &Path, records: &[Record]) -> Result<()> {
+    let io_error = |source| Error::Io { path: path.to_path_buf(), source };
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+    for record in records {
+        writeln!(file, "{}", record.to_json()).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+fn previous(history: &[Record], day: u32, stage: Stage) -> Option<Duration> {
+    history.iter().rev().find(|record| record.day == day && record.stage == stage).map(|record| record.stats.median)
+}
+
+pub fn bench(args: BenchArgs, history_path: &Path) -> Result<bool> {
+    let requested = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let history = load_history(history_path)?;
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or_default();
+
+    let mut results: Vec<BenchResult> = vec![];
+    let mut success = true;
+
+    for day in days::select(&args.days)? {
+        let answers = day.input.answers()?;
+        let parts = bench::bench_parts(&answers, &requested);
+        if parts.is_empty() {
+            aoc_common::warn!("Day {}: no known answer in data/answers.toml, skipped", day.number);
+            continue;
+        }
+
+        let run = |input: &str, parts: &[u8]| (day.run)(input, parts, None);
+        let measured = day.load(None, None).and_then(|contents| days::caught(|| bench::measure(day.number, run, &contents, &answers, &parts, args.runs)));
+        match measured {
+            Ok(measured) => results.extend(measured),
+            Err(error) => {
+                aoc_common::error!("Day {}: {}", day.number, report::render_error(&error));
+                success = false;
+            }
+        }
+    }
+
+    for result in results.iter_mut() {
+        result.previous = previous(&history, result.day, result.stage);
+    }
+    print!("{}", bench::format_bench(&results));
+
+    if args.save && !results.is_empty() {
+        let records: Vec<Record> = results.iter().map(|result| Record { time, day: result.day, stage: result.stage, runs: result.runs, stats: result.stats }).collect();
+        append_history(history_path, &records)?;
+        aoc_common::info!("Saved to {}", history_path.display());
+    }
+    Ok(success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u32, stage: Stage, median: u64) -> Record {
+        let median = Duration::from_micros(median);
+        Record { time: 1_700_000_000, day, stage, runs: 10, stats: Stats { median, min: median / 2, max: median * 2 } }
+    }
+
+    #[test]
+    fn test_record_json() {
+        let record = record(8, Stage::Part(1), 120);
+        let line = record.to_json();
+        assert_eq!(line, "{\"time\": 1700000000, \"day\": 8, \"stage\": \"part1\", \"runs\": 10, \"median_ns\": 120000, \"min_ns\": 60000, \"max_ns\": 240000}");
+        assert_eq!(Record::from_json(&line).unwrap(), record);
+        assert!(Record::from_json("{\"day\": 8}").is_err());
+    }
+
+    #[test]
+    fn test_previous() {
+        let history = vec![record(8, Stage::Parse, 100), record(8, Stage::Part(1), 200), record(8, Stage::Parse, 150)];
+        assert_eq!(previous(&history, 8, Stage::Parse), Some(Duration::from_micros(150)));
+        assert_eq!(previous(&history, 8, Stage::Part(2)), None);
+        assert_eq!(previous(&history, 7, Stage::Parse), None);
+    }
+
+    #[test]
+    fn test_load_history() {
+        let path = std::env::temp_dir().join(format!("aoc_bench_history_{}.jsonl", std::process::id()));
+        assert!(load_history(&path).unwrap().is_empty());
+
+        let records = vec![record(1, Stage::Parse, 10), record(1, Stage::Part(2), 20)];
+        append_history(&path, &records[..1]).unwrap();
+        append_history(&path, &records[1..]).unwrap();
+        assert_eq!(load_history(&path).unwrap(), records);
+
+        std::fs::write(&path, "{\"day\": 1}\n").unwrap();
+        let error = report::render_error(&load_history(&path).unwrap_err());
+        assert!(error.contains("line 1, column 1"), "{}", error);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
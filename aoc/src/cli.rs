@@ -0,0 +1,408 @@
+use std::time::Duration;
+
+use aoc_common::args::next_value;
+use aoc_common::bench::DEFAULT_RUNS;
+use aoc_common::report::Format;
+use aoc_common::{Error, Result};
+
+pub enum Command {
+    Run(RunArgs),
+    Verify(RunArgs),
+    Bench(BenchArgs),
+    Gen(GenArgs),
+    Watch(WatchArgs),
+    Serve(ServeArgs),
+    New(u32),
+    Help,
+}
+
+pub struct RunArgs {
+    pub days: Vec<u32>,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+    pub example: Option<u32>,
+    pub format: Format,
+    pub timeout: Option<Duration>,
+}
+
+pub struct BenchArgs {
+    pub days: Vec<u32>,
+    pub part: Option<u8>,
+    pub runs: usize,
+    pub save: bool,
+}
+
+pub struct GenArgs {
+    pub day: u32,
+    pub seed: u64,
+    pub size: usize,
+    pub output: Option<String>,
+}
+
+pub struct WatchArgs {
+    pub day: u32,
+}
+
+pub struct ServeArgs {
+    pub port: u16,
+    pub timeout: Option<Duration>,
+}
+
+pub const DEFAULT_PORT: u16 = 8080;
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+pub const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run       Run the solvers and print a table of answers and timings
+  verify    Check the answers against day_<n>/data/answers.toml
+  bench     Time parse, part 1 and part 2 over several runs and record them in bench_history.jsonl
+  gen       Print a random input for a day, the same for a given seed and size
+  watch     Rerun a day's example tests and input whenever its src/ or data/ changes
+  serve     Answer POST /day/<n>/part/<p> requests on localhost, the body being the puzzle input
+  new <n>   Create day_<n> from a template and register it in the workspace and the runner
+
+Options for every command:
+  -v, -vv         Print debug, then trace diagnostics of the solvers on stderr
+  -q, --quiet     Only print errors on stderr
+
+Options for run and verify:
+  --day <days>    Days to run, e.g. 7, 1,3,5 or 1-12 (default: all)
+  --part <part>   Only run part 1 or 2 (default: both)
+  --input <path>  Input file for a single --day, '-' reads stdin, run only
+                  (default: $AOC_INPUT_DIR/day_<n>.txt, then day_<n>/data/input.txt)
+  --example <n>   Run a single --day on its data/examples/<n>.txt, run only
+  --timeout <t>   Time limit of parse and of each part, e.g. 30, 2.5s or 500ms (default: none)
+  --format <fmt>  Output format: text, json or csv (default: text), run only
+
+Options for bench:
+  --day <days>    Days to benchmark (default: all)
+  --part <part>   Only benchmark part 1 or 2 (default: both, parts without a known answer are skipped)
+  --runs <n>      Number of runs per day (default: 10)
+  --no-save       Do not append the results to the history
+
+Options for gen:
+  --day <n>       Day to generate an input for (required)
+  --seed <n>      Seed of the generator (default: 0)
+  --size <n>      Number of lines, ranges or cells the input scales with (default: 1000)
+  --output <path> Write the input to <path> instead of stdout
+
+Options for watch:
+  --day <n>       Day to watch (required)
+
+Options for serve:
+  --port <n>      Port to listen on (default: 8080)
+  --timeout <t>   Time limit of parse and of the part of every request (default: none)";
+
+pub fn parse_args(args: &[String]) -> Result<Command> {
+    let Some(command) = args.first() else {
+        return Ok(Command::Help);
+    };
+
+    match command.as_str() {
+        "run" => Ok(Command::Run(parse_run_args(&args[1..])?)),
+        "verify" => {
+            let verify_args = parse_run_args(&args[1..])?;
+            if verify_args.input.is_some() || verify_args.example.is_some() {
+                return Err(Error::Usage("verify always uses the real inputs, --input and --example are not allowed".to_string()));
+            }
+            if verify_args.format != Format::Text {
+                return Err(Error::Usage("--format is only supported by run".to_string()));
+            }
+            Ok(Command::Verify(verify_args))
+        }
+        "bench" => Ok(Command::Bench(parse_bench_args(&args[1..])?)),
+        "gen" => Ok(Command::Gen(parse_gen_args(&args[1..])?)),
+        "watch" => Ok(Command::Watch(parse_watch_args(&args[1..])?)),
+        "serve" => Ok(Command::Serve(parse_serve_args(&args[1..])?)),
+        "new" => match &args[1..] {
+            [day] => Ok(Command::New(parse_day(day)?)),
+            _ => Err(Error::Usage(format!("new expects a single day\n\n{}", USAGE))),
+        },
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        _ => Err(Error::Usage(format!("unknown command '{}'\n\n{}", command, USAGE))),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs> {
+    let mut run_args = RunArgs { days: vec![], part: None, input: None, example: None, format: Format::Text, timeout: None };
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--day" | "-d" => run_args.days.extend(parse_days(next_value(&mut it, arg)?)?),
+            "--part" | "-p" => run_args.part = Some(parse_part(next_value(&mut it, arg)?)?),
+            "--input" | "-i" => run_args.input = Some(next_value(&mut it, arg)?.to_string()),
+            "--example" | "-e" => {
+                let value = next_value(&mut it, arg)?;
+                run_args.example = Some(value.parse().map_err(|_| Error::Usage(format!("invalid example '{}'", value)))?);
+            }
+            "--format" | "-f" => run_args.format = Format::parse(next_value(&mut it, arg)?)?,
+            "--timeout" | "-t" => run_args.timeout = Some(parse_timeout(next_value(&mut it, arg)?)?),
+            _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, USAGE))),
+        }
+    }
+
+    if run_args.input.is_some() && run_args.example.is_some() {
+        return Err(Error::Usage("--input and --example cannot be combined".to_string()));
+    }
+    if (run_args.input.is_some() || run_args.example.is_some()) && run_args.days.len() != 1 {
+        return Err(Error::Usage("--input and --example need exactly one --day".to_string()));
+    }
+    Ok(run_args)
+}
+
+// Seconds by default, or with an `s` or `ms` suffix
+fn parse_timeout(string: &str) -> Result<Duration> {
+    let (number, scale) = match string.strip_suffix("ms") {
+        Some(number) => (number, 1e-3),
+        None => (string.strip_suffix('s').unwrap_or(string), 1.0),
+    };
+    let invalid = || Error::Usage(format!("invalid timeout '{}'", string));
+    match number.trim().parse::<f64>() {
+        Ok(value) if value > 0.0 => Duration::try_from_secs_f64(value * scale).map_err(|_| invalid()),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_day(string: &str) -> Result<u32> {
+    match string.trim().parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(Error::Usage(format!("invalid day '{}'", string))),
+    }
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs> {
+    let mut day = None;
+    let mut gen_args = GenArgs { day: 0, seed: 0, size: DEFAULT_SIZE, output: None };
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(next_value(&mut it, arg)?)?),
+            "--seed" | "-s" => {
+                let value = next_value(&mut it, arg)?;
+                gen_args.seed = value.parse().map_err(|_| Error::Usage(format!("invalid seed '{}'", value)))?;
+            }
+            "--size" | "-n" => {
+                let value = next_value(&mut it, arg)?;
+                gen_args.size = match value.parse() {
+                    Ok(size) if size > 0 => size,
+                    _ => return Err(Error::Usage(format!("invalid size '{}'", value))),
+                };
+            }
+            "--output" | "-o" => gen_args.output = Some(next_value(&mut it, arg)?.to_string()),
+            _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, USAGE))),
+        }
+    }
+
+    gen_args.day = day.ok_or_else(|| Error::Usage("gen needs a --day".to_string()))?;
+    Ok(gen_args)
+}
+
+fn parse_watch_args(args: &[String]) -> Result<WatchArgs> {
+    let mut day = None;
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(next_value(&mut it, arg)?)?),
+            _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, USAGE))),
+        }
+    }
+    Ok(WatchArgs { day: day.ok_or_else(|| Error::Usage("watch needs a --day".to_string()))? })
+}
+
+fn parse_serve_args(args: &[String]) -> Result<ServeArgs> {
+    let mut serve_args = ServeArgs { port: DEFAULT_PORT, timeout: None };
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--port" => {
+                let value = next_value(&mut it, arg)?;
+                serve_args.port = value.parse().map_err(|_| Error::Usage(format!("invalid port '{}'", value)))?;
+            }
+            "--timeout" | "-t" => serve_args.timeout = Some(parse_timeout(next_value(&mut it, arg)?)?),
+            _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, USAGE))),
+        }
+    }
+    Ok(serve_args)
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs> {
+    let mut bench_args = BenchArgs { days: vec![], part: None, runs: DEFAULT_RUNS, save: true };
+    let mut it = args.iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--day" | "-d" => bench_args.days.extend(parse_days(next_value(&mut it, arg)?)?),
+            "--part" | "-p" => bench_args.part = Some(parse_part(next_value(&mut it, arg)?)?),
+            "--runs" | "-n" => {
+                let value = next_value(&mut it, arg)?;
+                bench_args.runs = match value.parse() {
+                    Ok(runs) if runs > 0 => runs,
+                    _ => return Err(Error::Usage(format!("invalid number of runs '{}'", value))),
+                };
+            }
+            "--no-save" => bench_args.save = false,
+            _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, USAGE))),
+        }
+    }
+    Ok(bench_args)
+}
+
+// Accepts a single day, a comma separated list and inclusive ranges: "7", "1,3,5", "1-5,9"
+pub fn parse_days(string: &str) -> Result<Vec<u32>> {
+    let mut days = vec![];
+
+    for item in string.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => days.extend(parse_day(start)?..=parse_day(end)?),
+            None => days.push(parse_day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+fn parse_part(string: &str) -> Result<u8> {
+    match string {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(Error::Usage(format!("invalid part '{}', expected 1 or 2", string))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7").unwrap(), vec![7]);
+        assert_eq!(parse_days("1,3,5").unwrap(), vec![1, 3, 5]);
+        assert_eq!(parse_days("1-3,9").unwrap(), vec![1, 2, 3, 9]);
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("0").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_args() {
+        let args: Vec<String> = ["run", "--day", "7", "--part", "2"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Run(run_args) => {
+                assert_eq!(run_args.days, vec![7]);
+                assert_eq!(run_args.part, Some(2));
+            }
+            _ => panic!("expected run command"),
+        }
+        assert!(parse_args(&["run".to_string(), "--part".to_string(), "3".to_string()]).is_err());
+
+        let args: Vec<String> = ["run", "--input", "-"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+        let args: Vec<String> = ["run", "-d", "3", "--input", "-"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_ok());
+
+        let args: Vec<String> = ["run", "--day", "7", "--example", "1"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Run(run_args) => assert_eq!(run_args.example, Some(1)),
+            _ => panic!("expected run command"),
+        }
+        let args: Vec<String> = ["run", "--example", "1"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+        let args: Vec<String> = ["run", "-d", "7", "-e", "1", "-i", "-"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+
+        let args: Vec<String> = ["run", "--format", "csv"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Run(run_args) => assert_eq!(run_args.format, Format::Csv),
+            _ => panic!("expected run command"),
+        }
+        let args: Vec<String> = ["run", "--timeout", "500ms"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Run(run_args) => assert_eq!(run_args.timeout, Some(Duration::from_millis(500))),
+            _ => panic!("expected run command"),
+        }
+        assert_eq!(parse_timeout("2.5").unwrap(), Duration::from_millis(2500));
+        assert_eq!(parse_timeout("30s").unwrap(), Duration::from_secs(30));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("soon").is_err());
+        assert!(parse_timeout("1e30").is_err());
+        assert!(parse_timeout("inf").is_err());
+
+        let args: Vec<String> = ["verify", "--format", "json"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_gen_args() {
+        let args: Vec<String> = ["gen", "--day", "8", "--seed", "42", "--size", "5000"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Gen(gen_args) => assert_eq!((gen_args.day, gen_args.seed, gen_args.size), (8, 42, 5000)),
+            _ => panic!("expected gen command"),
+        }
+        assert!(parse_args(&["gen".to_string()]).is_err());
+        let args: Vec<String> = ["gen", "-d", "8", "--size", "0"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let args: Vec<String> = ["bench", "--day", "8", "--runs", "3", "--no-save"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Bench(bench_args) => {
+                assert_eq!(bench_args.days, vec![8]);
+                assert_eq!(bench_args.runs, 3);
+                assert!(!bench_args.save);
+            }
+            _ => panic!("expected bench command"),
+        }
+        let args: Vec<String> = ["bench", "--runs", "0"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch_args() {
+        let args: Vec<String> = ["watch", "--day", "7"].iter().map(|s| s.to_string()).collect();
+        assert!(matches!(parse_args(&args).unwrap(), Command::Watch(WatchArgs { day: 7 })));
+        assert!(parse_args(&["watch".to_string()]).is_err());
+        let args: Vec<String> = ["watch", "--day", "1,2"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve_args() {
+        let args: Vec<String> = ["serve", "--port", "9000", "--timeout", "5s"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Serve(serve_args) => assert_eq!((serve_args.port, serve_args.timeout), (9000, Some(Duration::from_secs(5)))),
+            _ => panic!("expected serve command"),
+        }
+        match parse_args(&["serve".to_string()]).unwrap() {
+            Command::Serve(serve_args) => assert_eq!((serve_args.port, serve_args.timeout), (DEFAULT_PORT, None)),
+            _ => panic!("expected serve command"),
+        }
+        let args: Vec<String> = ["serve", "--port", "70000"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_args() {
+        let args: Vec<String> = ["new", "13"].iter().map(|s| s.to_string()).collect();
+        assert!(matches!(parse_args(&args).unwrap(), Command::New(13)));
+        assert!(parse_args(&["new".to_string()]).is_err());
+        assert!(parse_args(&["new".to_string(), "x".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        let args: Vec<String> = ["verify", "--day", "1-3"].iter().map(|s| s.to_string()).collect();
+        match parse_args(&args).unwrap() {
+            Command::Verify(verify_args) => assert_eq!(verify_args.days, vec![1, 2, 3]),
+            _ => panic!("expected verify command"),
+        }
+        let args: Vec<String> = ["verify", "-d", "3", "--input", "-"].iter().map(|s| s.to_string()).collect();
+        assert!(parse_args(&args).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::panic;
+use std::time::Duration;
+
+use aoc_common::input::DayInput;
+use aoc_common::solution::{self, DayRun, Solution};
+use aoc_common::{Error, Result};
+
+pub type RunFn = fn(&str, &[u8], Option<Duration>) -> Result<DayRun>;
+pub type GenerateFn = fn(u64, usize) -> String;
+
+pub struct Day {
+    pub number: u32,
+    pub input: DayInput,
+    pub run: RunFn,
+    pub generate: GenerateFn,
+}
+
+// A panicking solver is reported instead of aborting the other days
+pub fn caught<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(panic::AssertUnwindSafe(f)).unwrap_or(Err(Error::Panicked))
+}
+
+impl Day {
+    pub fn load(&self, input: Option<&str>, example: Option<u32>) -> Result<String> {
+        match example {
+            Some(example) => self.input.example(example),
+            None => self.input.load(input),
+        }
+    }
+
+    // Loads the input or an example and runs the parts, each stage limited to `timeout`
+    pub fn solve(&self, input: Option<&str>, example: Option<u32>, parts: &[u8], timeout: Option<Duration>) -> Result<DayRun> {
+        let contents = self.load(input, example)?;
+        caught(|| (self.run)(&contents, parts, timeout))
+    }
+}
+
+fn day<S: Solution>() -> Day {
+    Day { number: S::DAY, input: S::INPUT, run: solution::run_with_timeout::<S>, generate: solution::generate::<S> }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        day::<day_1::Day1>(),
+        day::<day_2::Day2>(),
+        day::<day_3::Day3>(),
+        day::<day_4::Day4>(),
+        day::<day_5::Day5>(),
+        day::<day_6::Day6>(),
+        day::<day_7::Day7>(),
+        day::<day_8::Day8>(),
+        day::<day_9::Day9>(),
+        day::<day_10::Day10>(),
+        day::<day_11::Day11>(),
+        day::<day_12::Day12>(),
+    ]
+}
+
+pub fn get_day(number: u32) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
+
+// Every day when `numbers` is empty
+pub fn select(numbers: &[u32]) -> Result<Vec<Day>> {
+    if numbers.is_empty() {
+        return Ok(all());
+    }
+    numbers.iter().map(|number| get_day(*number).ok_or_else(|| Error::Usage(format!("day {} is not solved yet", number)))).collect()
+}
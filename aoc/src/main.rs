@@ -0,0 +1,90 @@
+mod bench;
+mod cli;
+mod days;
+mod new;
+mod serve;
+mod verify;
+mod watch;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc_common::log;
+use aoc_common::report::{self, PartResult};
+use aoc_common::{Error, Result};
+
+use cli::{Command, GenArgs, RunArgs};
+
+// The runner lives in <root>/aoc
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn run(args: RunArgs) -> Result<bool> {
+    let mut results = vec![];
+    let mut success = true;
+
+    for day in days::select(&args.days)? {
+        let parts = match (args.part, args.example) {
+            (Some(part), _) => vec![part],
+            // Examples often only apply to one part, only run those with an expected answer
+            (None, Some(example)) => {
+                let parts = day.input.example_answers(example)?.parts();
+                if parts.is_empty() { vec![1, 2] } else { parts }
+            }
+            (None, None) => vec![1, 2],
+        };
+
+        match day.solve(args.input.as_deref(), args.example, &parts, args.timeout) {
+            Ok(day_run) => results.extend(PartResult::from_day_run(day.number, day_run)),
+            Err(error) => {
+                aoc_common::error!("Day {}: {}", day.number, report::render_error(&error));
+                results.extend(PartResult::failed(day.number, &parts, &error));
+                success = false;
+            }
+        }
+    }
+
+    print!("{}", report::format_results(&results, args.format));
+    Ok(success)
+}
+
+fn generate(args: GenArgs) -> Result<bool> {
+    let days = days::select(&[args.day])?;
+    let input = (days[0].generate)(args.seed, args.size);
+    match args.output {
+        Some(path) => fs::write(&path, input).map_err(|source| Error::Io { path: PathBuf::from(path), source })?,
+        None => print!("{}", input),
+    }
+    Ok(true)
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    log::set_level(report::exit_on_error(log::take_level(&mut args)));
+    let command = report::exit_on_error(cli::parse_args(&args));
+
+    let success = match command {
+        Command::Run(run_args) => report::exit_on_error(run(run_args)),
+        Command::Verify(verify_args) => report::exit_on_error(verify::verify(verify_args)),
+        Command::Bench(bench_args) => report::exit_on_error(bench::bench(bench_args, &workspace_root().join(bench::HISTORY_FILE))),
+        Command::Gen(gen_args) => report::exit_on_error(generate(gen_args)),
+        Command::Watch(watch_args) => report::exit_on_error(watch::watch(watch_args, &workspace_root())),
+        Command::Serve(serve_args) => report::exit_on_error(serve::serve(serve_args)),
+        Command::New(day) => {
+            let dir = report::exit_on_error(new::new_day(&workspace_root(), day));
+            println!("Created {}, paste the puzzle input in data/input.txt and the first example in data/examples/1.txt", dir.display());
+            true
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            true
+        }
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::read_file;
+use aoc_common::{Error, Result};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day_{day}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc_common.workspace = true
+
+[features]
+# Compile data/input.txt into the binary instead of reading it at runtime
+embed-input = []
+
+# Times the day on data/input.txt, `cargo bench -p day_{day} --bench bench -- --runs 20`
+[[bench]]
+name = "bench"
+harness = false
+"#;
+
+const BENCH_RS: &str = r#"fn main() {
+    aoc_common::bench::bench_main::<day_{day}::Day{day}>();
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{Answer, Result, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u32 = {day};
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_lines: &Vec<String>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day{day}>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day{day}>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day{day}>(2000);
+    }
+}
+"#;
+
+const FUZZ_TARGET_RS: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc_common::fuzz::check_parse::<day_{day}::Day{day}>(data));
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "day_{day}"
+path = "fuzz_targets/day_{day}.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+const MAIN_RS: &str = r#"use aoc_common::report;
+
+fn main() {
+    report::solve::<day_{day}::Day{day}>();
+}
+"#;
+
+const GITIGNORE: &str = "/target\nCargo.lock\n";
+
+const ANSWERS_TOML: &str = "# Answers on data/input.txt once accepted, checked by `aoc verify`\n# part1 = 0\n# part2 = 0\n";
+
+const EXAMPLE_TOML: &str = "# Expected answers of examples/1.txt, only for the parts it applies to\n# part1 = 0\n# part2 = 0\n";
+
+fn render(template: &str, day: u32) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+// Adds `entry` after the last line mentioning a day in the block opened by the `start` line,
+// or at the end of the block. The block ends at a blank line or a line starting with '[' or ']'.
+fn register(text: &str, start: &str, entry: &str) -> Result<String> {
+    let missing = || Error::Usage(format!("cannot find '{}' to register the new day", start));
+    let begin = text.find(start).ok_or_else(missing)?;
+    let mut offset = begin + text[begin..].find('\n').ok_or_else(missing)? + 1;
+    let mut last_day = None;
+
+    for line in text[offset..].split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('[') || trimmed.starts_with(']') {
+            break;
+        }
+        offset += line.len();
+        if trimmed.contains("day_") {
+            last_day = Some(offset);
+        }
+    }
+    let insert_at = last_day.unwrap_or(offset);
+    Ok(format!("{}{}\n{}", &text[..insert_at], entry, &text[insert_at..]))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+fn create_dir(path: &Path) -> Result<()> {
+    fs::create_dir_all(path).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+// Creates day_<day> with a failing Solution skeleton and registers it in the workspace, the runner and the fuzz targets
+pub fn new_day(root: &Path, day: u32) -> Result<PathBuf> {
+    let name = format!("day_{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::Usage(format!("{} already exists", dir.display())));
+    }
+
+    // Every registration is prepared before anything is written
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let fuzz_path = root.join("fuzz").join("Cargo.toml");
+    let workspace = register(&read_file(&workspace_path)?, "members = [", &format!("    \"{}\",", name))?;
+    let runner = read_file(&runner_path)?;
+    let runner = register(&runner, "[dependencies]", &format!("{} = {{ path = \"../{}\" }}", name, name))?;
+    let runner = register(&runner, "embed-input = [", &format!("    \"{}/embed-input\",", name))?;
+    let days = register(&read_file(&days_path)?, "vec![", &format!("        day::<{}::Day{}>(),", name, day))?;
+    let fuzz = register(&read_file(&fuzz_path)?, "[dependencies]", &format!("{} = {{ path = \"../{}\" }}", name, name))?;
+    let fuzz = format!("{}{}", fuzz, render(FUZZ_BIN, day));
+
+    create_dir(&dir.join("src"))?;
+    create_dir(&dir.join("benches"))?;
+    create_dir(&dir.join("data").join("examples"))?;
+    write(&dir.join("Cargo.toml"), &render(CARGO_TOML, day))?;
+    write(&dir.join(".gitignore"), GITIGNORE)?;
+    write(&dir.join("src").join("lib.rs"), &render(LIB_RS, day))?;
+    write(&dir.join("src").join("main.rs"), &render(MAIN_RS, day))?;
+    write(&dir.join("benches").join("bench.rs"), &render(BENCH_RS, day))?;
+    write(&dir.join("data").join("answers.toml"), ANSWERS_TOML)?;
+    write(&dir.join("data").join("examples").join("1.txt"), "")?;
+    write(&dir.join("data").join("examples").join("1.toml"), EXAMPLE_TOML)?;
+
+    write(&workspace_path, &workspace)?;
+    write(&runner_path, &runner)?;
+    write(&days_path, &days)?;
+    write(&fuzz_path, &fuzz)?;
+    write(&root.join("fuzz").join("fuzz_targets").join(format!("{}.rs", name)), &render(FUZZ_TARGET_RS, day))?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_1\",\n    \"day_2\",\n]\n\n[workspace.package]\n";
+        assert_eq!(
+            register(manifest, "members = [", "    \"day_3\",").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_1\",\n    \"day_2\",\n    \"day_3\",\n]\n\n[workspace.package]\n"
+        );
+
+        let runner = "[dependencies]\naoc_common.workspace = true\n\n[features]\n";
+        assert_eq!(
+            register(runner, "[dependencies]", "day_1 = { path = \"../day_1\" }").unwrap(),
+            "[dependencies]\naoc_common.workspace = true\nday_1 = { path = \"../day_1\" }\n\n[features]\n"
+        );
+        assert!(register(runner, "vec![", "").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let lib = render(LIB_RS, 13);
+        assert!(lib.contains("impl Solution for Day13 {"));
+        assert!(lib.contains("const DAY: u32 = 13;"));
+        assert!(render(MAIN_RS, 13).contains("report::solve::<day_13::Day13>();"));
+        assert!(render(FUZZ_TARGET_RS, 13).contains("check_parse::<day_13::Day13>(data)"));
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::json;
+use aoc_common::report::PartResult;
+use aoc_common::{Error, Result};
+
+use crate::cli::ServeArgs;
+use crate::days;
+
+// Puzzle inputs are a few dozen KiB, a body this large is a mistake
+const MAX_BODY: usize = 16 << 20;
+
+const ROUTE: &str = "POST /day/<n>/part/<p>";
+
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Response {
+        Response { status, body: json::object(&[("error", json::quote(message))]) }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            504 => "Gateway Timeout",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+// `/day/7/part/2`, a query string is ignored
+fn parse_route(path: &str) -> Option<(u32, u8)> {
+    let path = path.split('?').next().unwrap_or(path);
+    match path.trim_matches('/').split('/').collect::<Vec<&str>>()[..] {
+        ["day", day, "part", part @ ("1" | "2")] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+// The same row as `aoc run --format json`, with the error and where a parse error is in the input
+fn solve(number: u32, part: u8, input: &str, timeout: Option<Duration>) -> Response {
+    let Some(day) = days::get_day(number) else {
+        return Response::error(404, &format!("day {} is not solved yet", number));
+    };
+
+    let (result, error) = match days::caught(|| (day.run)(input, &[part], timeout)) {
+        Ok(day_run) => (PartResult::from_day_run(number, day_run).remove(0), None),
+        Err(error) => (PartResult::failed(number, &[part], &error).remove(0), Some(error)),
+    };
+    let (line, column) = match &error {
+        Some(Error::Parse(error)) if error.line > 0 => (error.line.to_string(), error.column.to_string()),
+        _ => ("null".to_string(), "null".to_string()),
+    };
+    let status = match &error {
+        None => 200,
+        Some(Error::Parse(_)) => 400,
+        Some(Error::TimedOut(_)) => 504,
+        Some(_) => 500,
+    };
+
+    let mut fields = result.json_fields();
+    fields.push(("error", error.map_or("null".to_string(), |error| json::quote(&error.to_string()))));
+    fields.push(("line", line));
+    fields.push(("column", column));
+    Response { status, body: json::object(&fields) }
+}
+
+fn route(request: &Request, timeout: Option<Duration>) -> Response {
+    let Some((day, part)) = parse_route(&request.path) else {
+        return Response::error(404, &format!("unknown path '{}', expected {}", request.path, ROUTE));
+    };
+    if request.method != "POST" {
+        return Response::error(405, &format!("expected {}", ROUTE));
+    }
+    match std::str::from_utf8(&request.body) {
+        Ok(input) => solve(day, part, input, timeout),
+        Err(_) => Response::error(400, "the puzzle input must be UTF-8"),
+    }
+}
+
+// HTTP/1.1 without chunked bodies or keep-alive, the response closes the connection
+fn read_request(reader: &mut impl BufRead) -> std::result::Result<Request, Response> {
+    let malformed = || Response::error(400, "malformed HTTP request");
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|_| malformed())?;
+    let (method, path) = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+        [method, path, version] if version.starts_with("HTTP/") => (method.to_string(), path.to_string()),
+        _ => return Err(malformed()),
+    };
+
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).map_err(|_| malformed())? == 0 {
+            return Err(malformed());
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').ok_or_else(malformed)?;
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            length = Some(value.trim().parse::<usize>().map_err(|_| malformed())?);
+        }
+    }
+
+    let length = match length {
+        Some(length) if length > MAX_BODY => return Err(Response::error(413, &format!("the body is limited to {} bytes", MAX_BODY))),
+        Some(length) => length,
+        None if method == "POST" => return Err(Response::error(411, "expected a Content-Length header")),
+        None => 0,
+    };
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|_| malformed())?;
+    Ok(Request { method, path, body })
+}
+
+fn write_response(writer: &mut impl Write, response: &Response) -> io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}\n",
+        response.status,
+        response.reason(),
+        response.body.len() + 1,
+        response.body
+    )?;
+    writer.flush()
+}
+
+fn handle(stream: TcpStream, timeout: Option<Duration>) -> io::Result<()> {
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => {
+            let response = route(&request, timeout);
+            aoc_common::debug!("{} {}: {}", request.method, request.path, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    write_response(&mut &stream, &response)
+}
+
+// Every connection gets its own thread, a slow day does not hold the others up
+fn accept(listener: TcpListener, timeout: Option<Duration>) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = handle(stream, timeout) {
+                        aoc_common::warn!("connection failed: {}", error);
+                    }
+                });
+            }
+            Err(error) => aoc_common::warn!("connection failed: {}", error),
+        }
+    }
+}
+
+// Only listens on the loopback interface, the API is meant for local tools
+pub fn serve(args: ServeArgs) -> Result<bool> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, args.port))
+        .map_err(|error| Error::Usage(format!("cannot listen on port {}: {}", args.port, error)))?;
+    let addr = listener.local_addr().map_err(|error| Error::Usage(format!("cannot listen on port {}: {}", args.port, error)))?;
+    aoc_common::info!("Listening on http://{}, {} with the puzzle input as the body", addr, ROUTE);
+    accept(listener, args.timeout);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::json::Value;
+    use std::io::Read;
+    use std::net::SocketAddr;
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request { method: method.to_string(), path: path.to_string(), body: body.as_bytes().to_vec() }
+    }
+
+    // A minimal client, sends one request and returns the status and body of the response
+    fn send(addr: SocketAddr, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.split_whitespace().nth(1).unwrap().parse().unwrap(), body.to_string())
+    }
+
+    #[test]
+    fn test_parse_route() {
+        assert_eq!(parse_route("/day/7/part/2"), Some((7, 2)));
+        assert_eq!(parse_route("/day/12/part/1/?verbose"), Some((12, 1)));
+        assert_eq!(parse_route("/day/7/part/3"), None);
+        assert_eq!(parse_route("/day/x/part/1"), None);
+        assert_eq!(parse_route("/"), None);
+    }
+
+    #[test]
+    fn test_route() {
+        let response = route(&request("POST", "/day/1/part/1", "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n"), None);
+        assert_eq!(response.status, 200);
+        let fields = json::parse_object(&response.body).unwrap();
+        assert_eq!(json::get(&fields, "answer").and_then(Value::as_str), Some("3"));
+        assert_eq!(json::get(&fields, "status").and_then(Value::as_str), Some("ok"));
+        assert!(json::get(&fields, "solve_ns").and_then(Value::as_u64).is_some());
+        assert_eq!(json::get(&fields, "error"), Some(&Value::Null));
+
+        let response = route(&request("POST", "/day/1/part/2", "L68\nR4x\n"), None);
+        assert_eq!(response.status, 400);
+        let fields = json::parse_object(&response.body).unwrap();
+        assert_eq!(json::get(&fields, "answer"), Some(&Value::Null));
+        assert_eq!(json::get(&fields, "status").and_then(Value::as_str), Some("error"));
+        assert_eq!((json::get(&fields, "line").and_then(Value::as_u64), json::get(&fields, "column").and_then(Value::as_u64)), (Some(2), Some(2)));
+        assert!(json::get(&fields, "error").and_then(Value::as_str).unwrap().contains("expected a number"));
+
+        assert_eq!(route(&request("GET", "/day/1/part/1", ""), None).status, 405);
+        assert_eq!(route(&request("POST", "/day/25/part/1", ""), None).status, 404);
+        assert_eq!(route(&request("POST", "/days", ""), None).status, 404);
+        assert_eq!(route(&Request { method: "POST".to_string(), path: "/day/1/part/1".to_string(), body: vec![0xff] }, None).status, 400);
+    }
+
+    #[test]
+    fn test_read_request() {
+        let request = read_request(&mut "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 4\r\n\r\nR48\nextra".as_bytes()).unwrap();
+        assert_eq!((request.method.as_str(), request.path.as_str(), request.body.as_slice()), ("POST", "/day/1/part/1", "R48\n".as_bytes()));
+        assert_eq!(read_request(&mut "GET / HTTP/1.1\r\n\r\n".as_bytes()).unwrap().body, Vec::<u8>::new());
+
+        assert_eq!(read_request(&mut "POST /day/1/part/1 HTTP/1.1\r\n\r\n".as_bytes()).unwrap_err().status, 411);
+        assert_eq!(read_request(&mut "POST / HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n".as_bytes()).unwrap_err().status, 413);
+        assert_eq!(read_request(&mut "POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nR48".as_bytes()).unwrap_err().status, 400);
+        assert_eq!(read_request(&mut "hello\r\n\r\n".as_bytes()).unwrap_err().status, 400);
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || accept(listener, Some(Duration::from_secs(10))));
+
+        let body = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+        let (status, response) = send(addr, &format!("POST /day/5/part/2 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}", body.len(), body));
+        assert_eq!(status, 200);
+        assert_eq!(json::get(&json::parse_object(&response).unwrap(), "answer").and_then(Value::as_str), Some("14"));
+
+        let (status, response) = send(addr, "PUT /day/5/part/2 HTTP/1.1\r\nContent-Length: 0\r\n\r\n");
+        assert_eq!(status, 405);
+        assert!(json::get(&json::parse_object(&response).unwrap(), "error").is_some());
+    }
+}
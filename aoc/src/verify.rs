@@ -0,0 +1,107 @@
+use std::fmt;
+
+use aoc_common::report;
+use aoc_common::{Answer, Result};
+
+use crate::cli::RunArgs;
+use crate::days;
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass(Answer),
+    Fail { expected: Answer, found: Answer },
+    // No known answer, the part is not run
+    Missing,
+    // The input could not be loaded or parsed, or the solver panicked
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass(answer) => write!(f, "{:<7}  {}", "ok", answer),
+            Status::Fail { expected, found } => write!(f, "{:<7}  {}, expected {}", "FAIL", found, expected),
+            Status::Missing => write!(f, "missing"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+pub struct Check {
+    pub day: u32,
+    pub part: u8,
+    pub status: Status,
+}
+
+pub fn format_checks(checks: &[Check]) -> String {
+    let count = |pass: fn(&Status) -> bool| checks.iter().filter(|check| pass(&check.status)).count();
+
+    let mut res = "Day  Part  Status\n".to_string();
+    for check in checks {
+        res += &format!("{:>3}  {:>4}  {}\n", check.day, check.part, check.status);
+    }
+    res += &format!(
+        "{} passed, {} failed, {} missing, {} errors\n",
+        count(|status| matches!(status, Status::Pass(_))),
+        count(|status| matches!(status, Status::Fail { .. })),
+        count(|status| *status == Status::Missing),
+        count(|status| *status == Status::Error),
+    );
+    res
+}
+
+// Runs the parts with a known answer on the real inputs, true when none failed
+pub fn verify(args: RunArgs) -> Result<bool> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut checks = vec![];
+
+    for day in days::select(&args.days)? {
+        let answers = day.input.answers()?;
+        let known: Vec<u8> = answers.parts().into_iter().filter(|part| parts.contains(part)).collect();
+
+        let mut found = vec![];
+        if !known.is_empty() {
+            match day.solve(None, None, &known, args.timeout) {
+                Ok(day_run) => found = day_run.parts,
+                Err(error) => aoc_common::error!("Day {}: {}", day.number, report::render_error(&error)),
+            }
+        }
+
+        for part in &parts {
+            let status = match (answers.get(*part), found.iter().find(|(other, _, _)| other == part)) {
+                (None, _) => Status::Missing,
+                (Some(_), None) => Status::Error,
+                (Some(expected), Some((_, answer, _))) if answer == expected => Status::Pass(answer.clone()),
+                (Some(expected), Some((_, answer, _))) => Status::Fail { expected: expected.clone(), found: answer.clone() },
+            };
+            checks.push(Check { day: day.number, part: *part, status });
+        }
+    }
+
+    print!("{}", format_checks(&checks));
+    Ok(checks.iter().all(|check| matches!(check.status, Status::Pass(_) | Status::Missing)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_checks() {
+        let checks = vec![
+            Check { day: 1, part: 1, status: Status::Pass(Answer::Number(1150)) },
+            Check { day: 1, part: 2, status: Status::Fail { expected: Answer::Number(6738), found: Answer::Number(6737) } },
+            Check { day: 12, part: 2, status: Status::Missing },
+        ];
+        assert_eq!(format_checks(&checks), concat!(
+            "Day  Part  Status\n",
+            "  1     1  ok       1150\n",
+            "  1     2  FAIL     6737, expected 6738\n",
+            " 12     2  missing\n",
+            "1 passed, 1 failed, 1 missing, 0 errors\n",
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::json::{self, Value};
+use aoc_common::{Error, ParseError, Result};
+
+use crate::cli::WatchArgs;
+use crate::days;
+
+// std has no file notifications, the watched files are polled instead
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Modification time of every file under the watched directories, sorted by path
+type Snapshot = Vec<(PathBuf, SystemTime)>;
+
+// The answer of each part, or its status when it has none
+type Answers = Vec<(u8, String)>;
+
+fn snapshot(dirs: &[PathBuf]) -> Result<Snapshot> {
+    let mut res = vec![];
+    let mut pending = dirs.to_vec();
+    while let Some(dir) = pending.pop() {
+        let io_error = |source| Error::Io { path: dir.clone(), source };
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            // A missing data/ directory is not an error, it may be created later
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(source) => return Err(io_error(source)),
+        };
+        for entry in entries {
+            let entry = entry.map_err(io_error)?;
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                // Editors save through temporary files, which can be gone by now
+                Err(source) if source.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(source) => return Err(io_error(source)),
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                res.push((entry.path(), metadata.modified().map_err(io_error)?));
+            }
+        }
+    }
+    res.sort();
+    Ok(res)
+}
+
+fn cargo(root: &Path, args: &[&str]) -> Result<Output> {
+    // Set when the runner itself was started by cargo
+    let program = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Command::new(&program).args(args).current_dir(root).stderr(Stdio::inherit()).output()
+        .map_err(|source| Error::Io { path: PathBuf::from(program), source })
+}
+
+// Reads the rows of `--format json`, one object per line
+fn parse_answers(output: &str) -> Result<Answers> {
+    output.lines().map(|line| line.trim().trim_end_matches(',')).filter(|line| line.starts_with('{')).map(|line| {
+        let fields = json::parse_object(line)?;
+        let part = json::get(&fields, "part").and_then(Value::as_u64).ok_or_else(|| ParseError::new(line, "expected a part"))?;
+        let answer = match (json::get(&fields, "answer").and_then(Value::as_str), json::get(&fields, "status").and_then(Value::as_str)) {
+            (Some(answer), _) => answer.to_string(),
+            (None, Some(status)) => status.to_string(),
+            (None, None) => return Err(ParseError::new(line, "expected an answer or a status").into()),
+        };
+        Ok((part as u8, answer))
+    }).collect()
+}
+
+// One line per part, comparing with the previous run when there was one
+fn diff_answers(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    current.iter().map(|(part, answer)| {
+        match previous.and_then(|previous| previous.iter().find(|(other, _)| other == part)) {
+            None => format!("  part {}: {}", part, answer),
+            Some((_, before)) if before == answer => format!("  part {}: {} (unchanged)", part, answer),
+            Some((_, before)) => format!("  part {}: {} -> {}", part, before, answer),
+        }
+    }).collect()
+}
+
+// Runs the example tests, then the day on its input. Answers of a failed run are None.
+fn rerun(root: &Path, day: u32) -> Result<Option<Answers>> {
+    let package = format!("day_{}", day);
+    let tests = cargo(root, &["test", "-q", "-p", &package, "--", "test_examples"])?;
+    if tests.status.success() {
+        println!("Day {} examples: ok", day);
+    } else {
+        print!("{}", String::from_utf8_lossy(&tests.stdout));
+        println!("Day {} examples: FAILED", day);
+    }
+
+    let run = cargo(root, &["run", "-q", "--release", "-p", &package, "--", "--format", "json"])?;
+    if !run.status.success() {
+        println!("Day {} input: FAILED", day);
+        return Ok(None);
+    }
+    Ok(Some(parse_answers(&String::from_utf8_lossy(&run.stdout))?))
+}
+
+// Reruns the day every time a file of its src/ or data/ changes, until interrupted
+pub fn watch(args: WatchArgs, root: &Path) -> Result<bool> {
+    let day = days::select(&[args.day])?.remove(0);
+    let data_dir = PathBuf::from(day.input.data_dir);
+    let dirs = [data_dir.with_file_name("src"), data_dir];
+    aoc_common::info!("Watching {}, press Ctrl-C to stop", dirs.iter().map(|dir| dir.display().to_string()).collect::<Vec<String>>().join(" and "));
+
+    let mut seen: Option<Snapshot> = None;
+    let mut last_answers: Option<Answers> = None;
+    loop {
+        let current = snapshot(&dirs)?;
+        if seen.as_ref() != Some(&current) {
+            if seen.is_some() {
+                // Editors often write a file in several steps, let them finish
+                thread::sleep(POLL_INTERVAL);
+                println!();
+            }
+            seen = Some(snapshot(&dirs)?);
+            if let Some(answers) = rerun(root, day.number)? {
+                diff_answers(last_answers.as_ref(), &answers).iter().for_each(|line| println!("{}", line));
+                last_answers = Some(answers);
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let output = "[\n  {\"day\": 12, \"part\": 1, \"answer\": \"519\", \"parse_ns\": 10, \"solve_ns\": 5, \"status\": \"ok\"},\n  \
+                      {\"day\": 12, \"part\": 2, \"answer\": null, \"parse_ns\": null, \"solve_ns\": 1, \"status\": \"unsolved\"}\n]\n";
+        assert_eq!(parse_answers(output).unwrap(), vec![(1, "519".to_string()), (2, "unsolved".to_string())]);
+        assert_eq!(parse_answers("[]\n").unwrap(), vec![]);
+        assert!(parse_answers("{\"day\": 12}").is_err());
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = vec![(1, "21".to_string()), (2, "40".to_string())];
+        let current = vec![(1, "21".to_string()), (2, "42".to_string())];
+        assert_eq!(diff_answers(Some(&previous), &current), vec!["  part 1: 21 (unchanged)", "  part 2: 40 -> 42"]);
+        assert_eq!(diff_answers(None, &current), vec!["  part 1: 21", "  part 2: 42"]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        fs::write(dir.join("sub").join("b.txt"), "b").unwrap();
+
+        let before = snapshot(&[dir.clone(), dir.join("missing")]).unwrap();
+        assert_eq!(before.iter().map(|(path, _)| path.clone()).collect::<Vec<PathBuf>>(), vec![dir.join("a.txt"), dir.join("sub").join("b.txt")]);
+        fs::write(dir.join("c.txt"), "c").unwrap();
+        assert_ne!(snapshot(std::slice::from_ref(&dir)).unwrap(), before);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
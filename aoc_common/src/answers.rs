@@ -0,0 +1,100 @@
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::read_file;
+use crate::parse;
+use crate::solution::Answer;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Known answers of a day, read from a small TOML subset:
+//   part1 = 1150
+//   part2 = "text answer"
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    // Parts with a known answer
+    pub fn parts(&self) -> Vec<u8> {
+        [1, 2].into_iter().filter(|part| self.get(*part).is_some()).collect()
+    }
+
+    pub fn parse(text: &str) -> Result<Answers> {
+        Answers::parse_lines(text).map_err(|error| match error {
+            Error::Parse(error) => Error::Parse(error.locate(text)),
+            error => error,
+        })
+    }
+
+    fn parse_lines(text: &str) -> Result<Answers> {
+        let mut answers = Answers::default();
+
+        for line in text.lines() {
+            let line = line.split_once('#').map_or(line, |(content, _)| content).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = parse::split_once(line, '=', "an answer like 'part1 = 1150'")?;
+            let (key, value) = (key.trim(), value.trim());
+            let answer = match value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) {
+                Some(text) => Answer::Text(text.to_string()),
+                None => Answer::Number(parse::number(value)?),
+            };
+
+            match key {
+                "part1" => answers.part1 = Some(answer),
+                "part2" => answers.part2 = Some(answer),
+                _ => return Err(ParseError::new(key, format!("expected 'part1' or 'part2', found '{}'", key)).into()),
+            }
+        }
+        Ok(answers)
+    }
+
+    // A day without an answers file simply has no known answers
+    pub fn load(path: &Path) -> Result<Answers> {
+        match read_file(path) {
+            Ok(text) => Answers::parse(&text),
+            Err(Error::Io { source, .. }) if source.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day 1\npart1 = 1150\npart2 = \"abc\" # text\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::Number(1150)));
+        assert_eq!(answers.get(2), Some(&Answer::Text("abc".to_string())));
+        assert_eq!(answers.parts(), vec![1, 2]);
+        assert_eq!(Answers::parse("part1 = 541").unwrap().get(2), None);
+        assert_eq!(Answers::parse("part2 = 2").unwrap().parts(), vec![2]);
+
+        match Answers::parse("part1 = 1150\npart3 = 2") {
+            Err(Error::Parse(error)) => assert_eq!((error.line, error.column), (2, 1)),
+            _ => panic!("expected a parse error"),
+        }
+        assert!(Answers::parse("part1 = x").is_err());
+    }
+
+    #[test]
+    fn test_load_missing() {
+        assert_eq!(Answers::load(Path::new("does/not/exist.toml")).unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,95 @@
+use std::env;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::log::{self, Level};
+use crate::report::Format;
+
+pub const DAY_USAGE: &str = "\
+Options:
+  --input <path>  Read the puzzle input from <path>, or from stdin with '-'
+                  (default: $AOC_INPUT_DIR/<day>.txt, then the day's data/input.txt)
+  --format <fmt>  Output format: text, json or csv (default: text)
+  -v, -vv         Print debug, then trace diagnostics on stderr
+  -q, --quiet     Only print errors on stderr";
+
+pub struct DayArgs {
+    pub input: Option<String>,
+    pub format: Format,
+    pub level: Level,
+}
+
+impl DayArgs {
+    pub fn parse(args: &[String]) -> Result<DayArgs> {
+        let mut args = args.to_vec();
+        let level = log::take_level(&mut args)?;
+        let mut day_args = DayArgs { input: None, format: Format::Text, level };
+        let mut it = args.iter();
+
+        while let Some(arg) = it.next() {
+            match arg.as_str() {
+                "--input" | "-i" => day_args.input = Some(next_value(&mut it, arg)?.to_string()),
+                "--format" | "-f" => day_args.format = Format::parse(next_value(&mut it, arg)?)?,
+                _ => return Err(Error::Usage(format!("unknown option '{}'\n\n{}", arg, DAY_USAGE))),
+            }
+        }
+        Ok(day_args)
+    }
+
+    pub fn from_env() -> Result<DayArgs> {
+        DayArgs::parse(&env::args().skip(1).collect::<Vec<String>>())
+    }
+}
+
+pub fn next_value<'a>(it: &mut impl Iterator<Item = &'a String>, option: &str) -> Result<&'a str> {
+    it.next().map(|value| value.as_str()).ok_or_else(|| Error::Usage(format!("missing value for '{}'", option)))
+}
+
+// Day specific options are taken out of the arguments before `DayArgs::parse` sees them
+pub fn take_flag(args: &mut Vec<String>, option: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != option);
+    args.len() != len
+}
+
+pub fn take_value<T: FromStr>(args: &mut Vec<String>, option: &str) -> Result<Option<T>> {
+    let Some(index) = args.iter().position(|arg| arg == option) else {
+        return Ok(None);
+    };
+    let value = args.drain(index..(index + 2).min(args.len())).nth(1).unwrap_or_default();
+    value.parse().map(Some).map_err(|_| Error::Usage(format!("invalid value '{}' for '{}'", value, option)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(DayArgs::parse(&to_args(&[])).unwrap().input, None);
+        assert_eq!(DayArgs::parse(&to_args(&["--input", "-"])).unwrap().input.as_deref(), Some("-"));
+        assert_eq!(DayArgs::parse(&to_args(&["-i", "example.txt"])).unwrap().input.as_deref(), Some("example.txt"));
+        assert!(DayArgs::parse(&to_args(&["--input"])).is_err());
+        assert!(DayArgs::parse(&to_args(&["--bogus"])).is_err());
+        assert_eq!(DayArgs::parse(&to_args(&["--format", "json"])).unwrap().format, Format::Json);
+        assert!(DayArgs::parse(&to_args(&["-f", "xml"])).is_err());
+        assert_eq!(DayArgs::parse(&to_args(&["-i", "-", "-vv"])).unwrap().level, Level::Trace);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut args = to_args(&["--trace", "--start", "12", "-i", "-"]);
+        assert!(take_flag(&mut args, "--trace"));
+        assert!(!take_flag(&mut args, "--trace"));
+        assert_eq!(take_value::<u32>(&mut args, "--start").unwrap(), Some(12));
+        assert_eq!(take_value::<u32>(&mut args, "--start").unwrap(), None);
+        assert_eq!(args, to_args(&["-i", "-"]));
+
+        assert!(take_value::<u32>(&mut to_args(&["--start", "x"]), "--start").is_err());
+        assert!(take_value::<u32>(&mut to_args(&["--start"]), "--start").is_err());
+    }
+}
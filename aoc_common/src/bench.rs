@@ -0,0 +1,169 @@
+use std::env;
+use std::fmt;
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::error::{Error, Result};
+use crate::report;
+use crate::solution::{self, DayRun, Solution};
+use crate::timing::{Elapsed, Stats};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Stage {
+    pub fn from_name(name: &str) -> Option<Stage> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Part(1)),
+            "part2" => Some(Stage::Part(2)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub day: u32,
+    pub stage: Stage,
+    pub runs: usize,
+    pub stats: Stats,
+    // Median of the last recorded benchmark of the same stage
+    pub previous: Option<Duration>,
+}
+
+// Only parts with a known answer are timed, an unfinished or wrong solver is not worth benchmarking.
+// `measure` checks the solver still gives that answer.
+pub fn bench_parts(answers: &Answers, requested: &[u8]) -> Vec<u8> {
+    answers.parts().into_iter().filter(|part| requested.contains(part)).collect()
+}
+
+// Runs the day `runs` times on the same input and summarizes every stage. Fails on the first answer that
+// differs from the known one.
+pub fn measure(day: u32, run: impl Fn(&str, &[u8]) -> Result<DayRun>, input: &str, answers: &Answers, parts: &[u8], runs: usize) -> Result<Vec<BenchResult>> {
+    let runs = runs.max(1);
+    let mut parse_samples = vec![];
+    let mut part_samples = vec![vec![]; parts.len()];
+
+    for _ in 0..runs {
+        let day_run = run(input, parts)?;
+        for (part, answer, _) in &day_run.parts {
+            if let Some(expected) = answers.get(*part).filter(|expected| *expected != answer) {
+                return Err(Error::WrongAnswer { part: *part, answer: answer.clone(), expected: expected.clone() });
+            }
+        }
+        parse_samples.push(day_run.parse);
+        for (samples, (_, _, elapsed)) in part_samples.iter_mut().zip(day_run.parts) {
+            samples.push(elapsed);
+        }
+    }
+
+    let stages = [Stage::Parse].into_iter().chain(parts.iter().map(|part| Stage::Part(*part)));
+    let samples = [parse_samples].into_iter().chain(part_samples);
+    Ok(stages
+        .zip(samples)
+        .map(|(stage, samples)| BenchResult { day, stage, runs, stats: Stats::from_samples(&samples).unwrap(), previous: None })
+        .collect())
+}
+
+pub fn format_bench(results: &[BenchResult]) -> String {
+    let mut res = format!("Day  Stage  {:>5}  {:>10}  {:>10}  {:>10}  {:>7}\n", "Runs", "Median", "Min", "Max", "Change");
+    for result in results {
+        let change = result.previous.map(|previous| format!("{:+.1}%", (result.stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0));
+        let line = format!(
+            "{:>3}  {:<5}  {:>5}  {:>10}  {:>10}  {:>10}  {:>7}",
+            result.day,
+            result.stage.to_string(),
+            result.runs,
+            Elapsed(result.stats.median).to_string(),
+            Elapsed(result.stats.min).to_string(),
+            Elapsed(result.stats.max).to_string(),
+            change.unwrap_or_default(),
+        );
+        res += line.trim_end();
+        res.push('\n');
+    }
+    res
+}
+
+// `cargo bench` passes its own flags, only `--runs <n>` is read
+fn runs_arg(args: &[String]) -> Result<usize> {
+    match args.iter().position(|arg| arg == "--runs") {
+        Some(index) => {
+            let value = args.get(index + 1).map(String::as_str).unwrap_or_default();
+            value.parse().map_err(|_| Error::Usage(format!("invalid value '{}' for '--runs'", value)))
+        }
+        None => Ok(DEFAULT_RUNS),
+    }
+}
+
+// Entry point of the days' `benches/bench.rs`
+pub fn bench_main<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let runs = report::exit_on_error(runs_arg(&args));
+    let answers = report::exit_on_error(S::INPUT.answers());
+    let parts = bench_parts(&answers, &[1, 2]);
+    if parts.is_empty() {
+        println!("No known answer in data/answers.toml, nothing to benchmark");
+        return;
+    }
+
+    let input = report::exit_on_error(S::INPUT.load(None));
+    let results = report::exit_on_error(measure(S::DAY, solution::run::<S>, &input, &answers, &parts, runs));
+    print!("{}", format_bench(&results));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_measure() {
+        let run = |input: &str, parts: &[u8]| -> Result<DayRun> {
+            let parts = parts.iter().map(|part| (*part, Answer::Number(input.len() as u64), Duration::from_micros(*part as u64 * 10))).collect();
+            Ok(DayRun { parse: Duration::from_micros(5), parts })
+        };
+        let answers = Answers { part1: None, part2: Some(Answer::Number(3)) };
+        let results = measure(3, run, "abc", &answers, &[2], 4).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].stage, results[0].stats.median), (Stage::Parse, Duration::from_micros(5)));
+        assert_eq!((results[1].stage, results[1].stats.median), (Stage::Part(2), Duration::from_micros(20)));
+        assert_eq!(results[1].runs, 4);
+
+        // A wrong answer is not timed
+        let Err(error) = measure(3, run, "abcd", &answers, &[2], 4) else { panic!("a wrong answer was benchmarked") };
+        assert!(matches!(error, Error::WrongAnswer { part: 2, .. }));
+        assert_eq!(error.to_string(), "part 2 answered 4, not the known 3");
+    }
+
+    #[test]
+    fn test_format_bench() {
+        let stats = Stats { median: Duration::from_micros(110), min: Duration::from_micros(100), max: Duration::from_micros(150) };
+        let results = vec![BenchResult { day: 8, stage: Stage::Part(1), runs: 10, stats, previous: Some(Duration::from_micros(100)) }];
+        let table = format_bench(&results);
+        assert_eq!(table.lines().nth(1), Some("  8  part1     10     110.0µs     100.0µs     150.0µs   +10.0%"));
+    }
+
+    #[test]
+    fn test_runs_arg() {
+        let args: Vec<String> = ["--bench", "--runs", "3"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(runs_arg(&args).unwrap(), 3);
+        assert_eq!(runs_arg(&args[..1]).unwrap(), DEFAULT_RUNS);
+        assert!(runs_arg(&args[..2]).is_err());
+        assert_eq!(Stage::from_name("part2"), Some(Stage::Part(2)));
+    }
+}
@@ -0,0 +1,110 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+// Shared flag a long computation polls to stop early
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+// Restores the previous token even when the solver panics
+struct Restore(Option<Token>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+    }
+}
+
+// Polled by the solvers' long loops, always false outside of `with_token`
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled))
+}
+
+// Runs `f` on this thread with `token` as the one `cancelled` checks
+pub fn with_token<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(CURRENT.with(|current| current.replace(Some(token.clone()))));
+    f()
+}
+
+// Cancels `f` once `timeout` elapses. Cancellation is cooperative: `f` still has to notice it and return,
+// whatever it returned is then replaced by Error::TimedOut.
+pub fn with_timeout<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> Result<T> {
+    let Some(timeout) = timeout else {
+        return Ok(f());
+    };
+
+    let token = Token::new();
+    let (done, finished) = mpsc::channel::<()>();
+    let watchdog = {
+        let token = token.clone();
+        thread::spawn(move || {
+            if finished.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+                token.cancel();
+            }
+        })
+    };
+
+    let res = with_token(&token, f);
+    // Disconnecting the channel wakes the watchdog up
+    drop(done);
+    watchdog.join().unwrap();
+
+    if token.is_cancelled() {
+        Err(Error::TimedOut(timeout))
+    } else {
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_timeout() {
+        let spin = || {
+            while !cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        };
+        match with_timeout(Some(Duration::from_millis(20)), spin) {
+            Err(Error::TimedOut(timeout)) => assert_eq!(timeout, Duration::from_millis(20)),
+            _ => panic!("expected a timeout"),
+        }
+        assert!(!cancelled());
+
+        assert_eq!(with_timeout(Some(Duration::from_secs(10)), || 42).unwrap(), 42);
+        assert!(!with_timeout(None, cancelled).unwrap());
+    }
+
+    #[test]
+    fn test_with_token() {
+        let token = Token::new();
+        token.cancel();
+        assert!(with_token(&token, cancelled));
+        assert!(!cancelled());
+    }
+}
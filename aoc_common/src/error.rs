@@ -0,0 +1,134 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::solution::Answer;
+use crate::timing::Elapsed;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    Parse(ParseError),
+    Usage(String),
+    // Values the puzzle rules out, like a dial without any position
+    Invalid(String),
+    WrongAnswer { part: u8, answer: Answer, expected: Answer },
+    TimedOut(Duration),
+    Panicked,
+}
+
+// Invalid puzzle input. Parsers only hand over the offending slice of the input,
+// `locate` later turns its address into a line and column (both 1-based, 0 until located)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    pub line: usize,
+    pub column: usize,
+    // The whole input line, kept to render the error
+    pub source_line: String,
+    address: usize,
+}
+
+impl ParseError {
+    // `text` should be a slice of the parsed input, an empty one points between two characters
+    pub fn new(text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            text: text.to_string(),
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.line > 0 || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+
+        let Some(before) = input.get(..self.address - start) else {
+            return self;
+        };
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[line_start..].find('\n').map_or(input.len(), |index| line_start + index);
+
+        self.line = before.matches('\n').count() + 1;
+        self.column = before[line_start..].chars().count() + 1;
+        self.source_line = input[line_start..line_end].trim_end_matches('\r').to_string();
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Parse(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "cannot read {}: {}", path.display(), source),
+            Error::Parse(error) => write!(f, "parse error: {}", error),
+            Error::Usage(message) | Error::Invalid(message) => write!(f, "{}", message),
+            Error::WrongAnswer { part, answer, expected } => write!(f, "part {} answered {}, not the known {}", part, answer, expected),
+            Error::TimedOut(timeout) => write!(f, "timed out after {}", Elapsed(*timeout)),
+            Error::Panicked => write!(f, "solver panicked"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(_)
+            | Error::Usage(_)
+            | Error::Invalid(_)
+            | Error::WrongAnswer { .. }
+            | Error::TimedOut(_)
+            | Error::Panicked => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "L68\nR4x\nL1";
+        let error = ParseError::new(&input[6..7], "expected a number").locate(input);
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.source_line, "R4x");
+        assert_eq!(error.to_string(), "line 2, column 3: expected a number");
+
+        let error = ParseError::new(&input[input.len()..], "missing turn").locate(input);
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+
+    #[test]
+    fn test_locate_outside_input() {
+        let other = String::from("R4x");
+        let error = ParseError::new(&other, "expected a number").locate("L68");
+        assert_eq!(error.line, 0);
+        assert_eq!(error.to_string(), "expected a number");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::error::Error;
+use crate::report;
+use crate::rng::Rng;
+use crate::solution::{self, Solution};
+
+// Bytes the puzzle inputs are made of, mutations mostly pick from these to get past the first checks of the parsers
+const INTERESTING: &[&str] = &[
+    "0", "1", "9", "-", ",", ":", "x", "#", ".", "@", "^", "S", "+", "*", "L", "R", "[", "]", "(", ")", "{", "}", " ",
+    "\n", "\r\n", "\t", "18446744073709551616", "é",
+];
+
+// Fuzz target body: parsing arbitrary bytes must give the input or a parse error, any panic is a bug
+pub fn check_parse<S: Solution>(data: &[u8]) {
+    // Inputs are read into a String, invalid UTF-8 never reaches the parsers
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    match solution::parse::<S>(text) {
+        Ok(_) => {}
+        Err(error @ Error::Parse(_)) => {
+            report::render_error(&error);
+        }
+        Err(error) => panic!("day {} failed to parse with a {:?} error", S::DAY, error),
+    }
+}
+
+// A few random edits of `input`: replaced, inserted, deleted or repeated bytes and truncation
+pub fn mutate(rng: &mut Rng, input: &str) -> Vec<u8> {
+    let mut bytes = input.as_bytes().to_vec();
+    let edits = rng.range(1..=4);
+    for _ in 0..edits {
+        let at = rng.below(bytes.len() as u64 + 1) as usize;
+        let piece: Vec<u8> = if rng.chance(0.8) {
+            rng.pick(INTERESTING).as_bytes().to_vec()
+        } else {
+            vec![rng.below(256) as u8]
+        };
+        match rng.below(5) {
+            0 if at < bytes.len() => {
+                bytes.splice(at..at + 1, piece);
+            }
+            1 => {
+                bytes.splice(at..at, piece);
+            }
+            2 => {
+                let len = rng.below((bytes.len() - at) as u64 + 1) as usize;
+                bytes.drain(at..at + len);
+            }
+            3 => {
+                let len = rng.below((bytes.len() - at).min(64) as u64 + 1) as usize;
+                let repeated = bytes[at..at + len].to_vec();
+                bytes.splice(at..at, repeated);
+            }
+            _ => bytes.truncate(at),
+        }
+    }
+    bytes
+}
+
+// Test harness: the same check as the fuzz targets on `count` mutations of generated inputs and examples
+pub fn check_mutations<S: Solution>(count: u64) {
+    let examples: Vec<String> = S::INPUT.examples().unwrap_or_default().into_iter().filter_map(|example| S::INPUT.example(example).ok()).collect();
+    for seed in 0..count {
+        let mut rng = Rng::new(seed);
+        let input = if seed % 2 == 1 && !examples.is_empty() {
+            rng.pick(&examples).clone()
+        } else {
+            let size = rng.range(1..=20) as usize;
+            S::generate(&mut rng, size)
+        };
+        check_parse::<S>(&mutate(&mut rng, &input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate() {
+        let input = "1,2\n3,4\n";
+        assert_eq!(mutate(&mut Rng::new(3), input), mutate(&mut Rng::new(3), input));
+        assert!((0..100).any(|seed| mutate(&mut Rng::new(seed), input) != input.as_bytes()));
+    }
+}
@@ -0,0 +1,167 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::error::{Error, Result};
+
+// Directory holding one <day>.txt per day, checked before the day's own data/input.txt
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const EXAMPLES_DIR: &str = "examples";
+
+// Where a day finds its puzzle input, built by `day_input!` inside the day's crate
+#[derive(Debug, Clone, Copy)]
+pub struct DayInput {
+    pub name: &'static str,
+    pub data_dir: &'static str,
+    // Set when the day is built with its `embed-input` feature
+    pub embedded: Option<&'static str>,
+}
+
+#[macro_export]
+macro_rules! day_input {
+    () => {
+        $crate::input::DayInput {
+            name: env!("CARGO_PKG_NAME"),
+            data_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/data"),
+            #[cfg(feature = "embed-input")]
+            embedded: Some(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/input.txt"))),
+            #[cfg(not(feature = "embed-input"))]
+            embedded: None,
+        }
+    };
+}
+
+impl DayInput {
+    pub fn data_path(&self, file: &str) -> PathBuf {
+        Path::new(self.data_dir).join(file)
+    }
+
+    pub fn answers(&self) -> Result<Answers> {
+        Answers::load(&self.data_path(ANSWERS_FILE))
+    }
+
+    // Puzzle examples are `data/examples/<n>.txt`, with their expected answers in `<n>.toml`
+    pub fn example_path(&self, example: u32, extension: &str) -> PathBuf {
+        self.data_path(EXAMPLES_DIR).join(format!("{}.{}", example, extension))
+    }
+
+    pub fn example(&self, example: u32) -> Result<String> {
+        read_file(&self.example_path(example, "txt"))
+    }
+
+    pub fn example_answers(&self, example: u32) -> Result<Answers> {
+        Answers::load(&self.example_path(example, "toml"))
+    }
+
+    // Example numbers in increasing order, none if the directory does not exist
+    pub fn examples(&self) -> Result<Vec<u32>> {
+        let dir = self.data_path(EXAMPLES_DIR);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(source) => return Err(Error::Io { path: dir, source }),
+        };
+
+        let mut examples = vec![];
+        for entry in entries {
+            let path = entry.map_err(|source| Error::Io { path: dir.clone(), source })?.path();
+            if path.extension().is_some_and(|extension| extension == "txt")
+                && let Some(example) = path.file_stem().and_then(|stem| stem.to_str()).and_then(|stem| stem.parse().ok())
+            {
+                examples.push(example);
+            }
+        }
+        examples.sort();
+        Ok(examples)
+    }
+
+    pub fn input_path(&self, input_dir: Option<&OsStr>) -> PathBuf {
+        match input_dir {
+            Some(dir) => Path::new(dir).join(format!("{}.txt", self.name)),
+            None => self.data_path("input.txt"),
+        }
+    }
+
+    // `input` is the --input argument: a path, or "-" for stdin. Returns where the reader reads from, for its errors.
+    pub fn open(&self, input: Option<&str>) -> Result<(PathBuf, Box<dyn BufRead>)> {
+        let path = match (input, env::var_os(INPUT_DIR_VAR), self.embedded) {
+            (Some("-"), _, _) => return Ok((PathBuf::from("<stdin>"), Box::new(io::stdin().lock()))),
+            (Some(path), _, _) => PathBuf::from(path),
+            (None, None, Some(embedded)) => return Ok((PathBuf::from("<embedded>"), Box::new(embedded.as_bytes()))),
+            (None, input_dir, _) => self.input_path(input_dir.as_deref()),
+        };
+        let file = fs::File::open(&path).map_err(|source| Error::Io { path: path.clone(), source })?;
+        Ok((path, Box::new(BufReader::new(file))))
+    }
+
+    pub fn load(&self, input: Option<&str>) -> Result<String> {
+        let (path, mut reader) = self.open(input)?;
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents).map_err(|source| Error::Io { path, source })?;
+        Ok(contents)
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+// LF line endings, no BOM, no trailing whitespace on lines nor trailing blank lines.
+// Leading whitespace is kept, some grids are aligned on it.
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut normalized = String::with_capacity(text.len());
+    for line in text.trim_end().lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_7: DayInput = DayInput { name: "day_7", data_dir: "/aoc/day_7/data", embedded: None };
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(DAY_7.input_path(Some(OsStr::new("/tmp/inputs"))), PathBuf::from("/tmp/inputs/day_7.txt"));
+        assert_eq!(DAY_7.input_path(None), PathBuf::from("/aoc/day_7/data/input.txt"));
+    }
+
+    #[test]
+    fn test_load() {
+        let error = DAY_7.load(Some("does/not/exist.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("cannot read does/not/exist.txt"));
+
+        let embedded = DayInput { embedded: Some("L68\n"), ..DAY_7 };
+        assert!(embedded.load(Some("does/not/exist.txt")).is_err());
+        assert_eq!(embedded.load(None).unwrap(), "L68\n");
+
+        let (path, mut reader) = embedded.open(None).unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!((path, line), (PathBuf::from("<embedded>"), "L68\n".to_string()));
+        assert!(matches!(DAY_7.open(Some("does/not/exist.txt")), Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(DAY_7.example_path(2, "toml"), PathBuf::from("/aoc/day_7/data/examples/2.toml"));
+        assert!(DAY_7.examples().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let expected = "3-5\n  10-14\n\n1\n";
+        assert_eq!(normalize("3-5\n  10-14\n\n1\n"), expected);
+        assert_eq!(normalize("3-5\r\n  10-14\r\n\r\n1\r\n"), expected);
+        assert_eq!(normalize("\u{feff}3-5 \r\n  10-14\t\n \n1\n\n\n"), expected);
+        assert_eq!(normalize("3-5\n  10-14\n\n1"), expected);
+        assert_eq!(normalize(""), "");
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt::Write;
+
+use crate::error::{ParseError, Result};
+
+// Just enough JSON for flat records: one object of strings, numbers, booleans and nulls per line
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(number) if *number >= 0.0 && number.fract() == 0.0 => Some(*number as u64),
+            _ => None,
+        }
+    }
+}
+
+pub fn quote(text: &str) -> String {
+    let mut res = String::with_capacity(text.len() + 2);
+    res.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(res, "\\u{:04x}", c as u32).unwrap(),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+// `{"day": 8, "stage": "parse"}`, values must already be valid JSON
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<String> = fields.iter().map(|(key, value)| format!("{}: {}", quote(key), value)).collect();
+    format!("{{{}}}", fields.join(", "))
+}
+
+pub fn array(values: &[String]) -> String {
+    format!("[{}]", values.join(", "))
+}
+
+pub fn get<'a>(fields: &'a [(String, Value)], key: &str) -> Option<&'a Value> {
+    fields.iter().find(|(other, _)| other == key).map(|(_, value)| value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    fn error(&self, message: &str) -> crate::Error {
+        let rest = self.rest();
+        ParseError::new(&rest[..rest.chars().next().map_or(0, char::len_utf8)], message).into()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.offset = self.text.len() - self.rest().trim_start().len();
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.offset += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut res = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.offset += index + 1;
+                    return Ok(res);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => res.push('\n'),
+                    Some('r') => res.push('\r'),
+                    Some('t') => res.push('\t'),
+                    Some('u') => {
+                        let code: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        res.push(u32::from_str_radix(&code, 16).ok().and_then(char::from_u32).unwrap_or('\u{fffd}'));
+                    }
+                    Some(c) => res.push(c),
+                    None => break,
+                },
+                c => res.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        for (word, value) in [("true", Value::Bool(true)), ("false", Value::Bool(false)), ("null", Value::Null)] {
+            if rest.starts_with(word) {
+                self.offset += word.len();
+                return Ok(value);
+            }
+        }
+        if rest.starts_with('"') {
+            return Ok(Value::String(self.string()?));
+        }
+
+        let len = rest.find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c))).unwrap_or(rest.len());
+        match rest[..len].parse() {
+            Ok(number) if len > 0 => {
+                self.offset += len;
+                Ok(Value::Number(number))
+            }
+            _ => Err(self.error("expected a string, a number, a boolean or null")),
+        }
+    }
+}
+
+pub fn parse_object(text: &str) -> Result<Vec<(String, Value)>> {
+    let mut parser = Parser { text, offset: 0 };
+    let mut fields = vec![];
+    parser.expect('{')?;
+    parser.skip_whitespace();
+    if parser.rest().starts_with('}') {
+        parser.offset += 1;
+    } else {
+        loop {
+            let key = parser.string()?;
+            parser.expect(':')?;
+            fields.push((key, parser.value()?));
+            parser.skip_whitespace();
+            if parser.rest().starts_with(',') {
+                parser.offset += 1;
+            } else {
+                parser.expect('}')?;
+                break;
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    if !parser.rest().is_empty() {
+        return Err(parser.error("unexpected text after the object"));
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        assert_eq!(quote("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(object(&[("day", "8".to_string()), ("stage", quote("parse"))]), "{\"day\": 8, \"stage\": \"parse\"}");
+        assert_eq!(array(&["1".to_string(), "2".to_string()]), "[1, 2]");
+    }
+
+    #[test]
+    fn test_parse_object() {
+        let fields = parse_object("{\"day\": 8, \"stage\": \"pa\\\"rse\", \"ok\": true, \"x\": null, \"t\": -1.5e3}").unwrap();
+        assert_eq!(get(&fields, "day").and_then(Value::as_u64), Some(8));
+        assert_eq!(get(&fields, "stage").and_then(Value::as_str), Some("pa\"rse"));
+        assert_eq!(get(&fields, "ok"), Some(&Value::Bool(true)));
+        assert_eq!(get(&fields, "x"), Some(&Value::Null));
+        assert_eq!(get(&fields, "t"), Some(&Value::Number(-1500.0)));
+        assert_eq!(parse_object(" { } ").unwrap(), vec![]);
+
+        let line = object(&[("text", quote("tab\there"))]);
+        assert_eq!(get(&parse_object(&line).unwrap(), "text").and_then(Value::as_str), Some("tab\there"));
+
+        assert!(parse_object("{\"day\": 8").is_err());
+        assert!(parse_object("{\"day\": [8]}").is_err());
+        assert!(parse_object("{\"day\": 8} x").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cancel;
+pub mod error;
+pub mod fuzz;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod parse;
+pub mod report;
+pub mod rng;
+pub mod solution;
+pub mod timing;
+
+pub use error::{Error, ParseError, Result};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::error::{Error, Result};
+
+// Diagnostics go to stderr so they never mix with the answers on stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Warn => write!(f, "warn"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::ALL[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+// The macros check it before evaluating their arguments, callers can also skip building dumps nobody will see
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+pub fn log(level: Level, args: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("[{}] {}", level, args);
+    }
+}
+
+// Removes -v, -vv, --verbose and -q/--quiet from the arguments and returns the level they select
+pub fn take_level(args: &mut Vec<String>) -> Result<Level> {
+    let mut verbosity = 0;
+    let mut quiet = false;
+    args.retain(|arg| {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "-q" | "--quiet" => quiet = true,
+            _ => return true,
+        }
+        false
+    });
+
+    match (quiet, verbosity) {
+        (true, 0) => Ok(Level::Error),
+        (true, _) => Err(Error::Usage("--quiet and --verbose cannot be combined".to_string())),
+        (false, verbosity) => Ok(Level::ALL[(Level::Info as usize + verbosity).min(Level::Trace as usize)]),
+    }
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Error) {
+            $crate::log::log($crate::log::Level::Error, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Warn) {
+            $crate::log::log($crate::log::Level::Warn, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            $crate::log::log($crate::log::Level::Info, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::log($crate::log::Level::Trace, format_args!($($arg)*))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take(args: &[&str]) -> (Result<Level>, Vec<String>) {
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        (take_level(&mut args), args)
+    }
+
+    #[test]
+    fn test_take_level() {
+        let (level, args) = take(&["--day", "7", "-v"]);
+        assert_eq!(level.unwrap(), Level::Debug);
+        assert_eq!(args, vec!["--day", "7"]);
+        assert_eq!(take(&[]).0.unwrap(), Level::Info);
+        assert_eq!(take(&["-vv"]).0.unwrap(), Level::Trace);
+        assert_eq!(take(&["-v", "-vv"]).0.unwrap(), Level::Trace);
+        assert_eq!(take(&["--quiet"]).0.unwrap(), Level::Error);
+        assert!(take(&["-q", "-v"]).0.is_err());
+        assert!(Level::Warn < Level::Debug);
+    }
+}
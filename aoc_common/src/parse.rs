@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use crate::error::{ParseError, Result};
+
+pub fn number<T: FromStr>(text: &str) -> Result<T> {
+    text.parse().map_err(|_| {
+        if text.is_empty() {
+            ParseError::new(text, "expected a number").into()
+        } else {
+            ParseError::new(text, format!("expected a number, found '{}'", text)).into()
+        }
+    })
+}
+
+// Empty slice right after `text`, to point at something missing
+pub fn end_of(text: &str) -> &str {
+    &text[text.len()..]
+}
+
+pub fn split_once<'a>(text: &'a str, separator: char, expected: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(separator).ok_or_else(|| ParseError::new(text, format!("expected {}", expected)).into())
+}
+
+// Blocks of lines separated by blank lines, whatever the line endings.
+// Sections are slices of `text` without their trailing line ending, so errors stay locatable.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&text[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&text[start..end]);
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>("42").unwrap(), 42);
+
+        let input = "12,x4";
+        match number::<u32>(&input[3..]) {
+            Err(Error::Parse(error)) => {
+                let error = error.locate(input);
+                assert_eq!(error.column, 4);
+                assert_eq!(error.message, "expected a number, found 'x4'");
+            }
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_split_once() {
+        assert_eq!(split_once("3-5", '-', "a range").unwrap(), ("3", "5"));
+        assert!(split_once("35", '-', "a range").is_err());
+        assert_eq!(end_of("abc"), "");
+    }
+
+    #[test]
+    fn test_sections() {
+        let expected = vec!["3-5\n10-14", "1\n5"];
+        assert_eq!(sections("3-5\n10-14\n\n1\n5\n"), expected);
+        assert_eq!(sections("\n\n3-5\n10-14\n  \n\n1\n5"), expected);
+
+        let crlf = sections("3-5\r\n10-14\r\n\r\n1\r\n5\r\n");
+        assert_eq!(crlf.len(), 2);
+        assert_eq!(crlf[0].lines().collect::<Vec<&str>>(), ["3-5", "10-14"]);
+        assert_eq!(crlf[1].lines().collect::<Vec<&str>>(), ["1", "5"]);
+
+        assert!(sections("").is_empty());
+    }
+}
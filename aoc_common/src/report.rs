@@ -0,0 +1,303 @@
+use std::fmt::{self, Display};
+use std::process;
+use std::time::Duration;
+
+use crate::args::DayArgs;
+use crate::error::{Error, ParseError, Result};
+use crate::json;
+use crate::log;
+use crate::solution::{self, Answer, DayRun, Solution};
+use crate::timing::{timed, Elapsed};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(string: &str) -> Result<Format> {
+        match string {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(Error::Usage(format!("invalid format '{}', expected text, json or csv", string))),
+        }
+    }
+}
+
+pub fn print_answer<T: Display>(part: u8, answer: T, elapsed: Duration) {
+    println!("P{}: {} ({})", part, answer, Elapsed(elapsed));
+}
+
+// Times one part of the puzzle and prints its answer
+pub fn part<T: Display>(part: u8, solve: impl FnOnce() -> T) {
+    let (answer, elapsed) = timed(solve);
+    print_answer(part, answer, elapsed);
+}
+
+// Parse errors get the offending line with carets under the offending text
+pub fn render_error(error: &Error) -> String {
+    match error {
+        Error::Parse(parse_error) if parse_error.line > 0 => format!("{}\n{}", error, render_snippet(parse_error)),
+        _ => error.to_string(),
+    }
+}
+
+fn render_snippet(error: &ParseError) -> String {
+    let number = error.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let carets = "^".repeat(error.text.chars().count().max(1));
+    let source_line = error.source_line.replace('\t', " ");
+    format!("{} |\n{} | {}\n{} | {}{}", gutter, number, source_line, gutter, " ".repeat(error.column - 1), carets)
+}
+
+pub fn exit_on_error<T>(res: Result<T>) -> T {
+    res.unwrap_or_else(|error| {
+        eprintln!("Error: {}", render_error(&error));
+        process::exit(1)
+    })
+}
+
+// Entry point of the day binaries: parse the input once, then print both parts
+pub fn solve<S: Solution>() {
+    solve_with::<S>(&exit_on_error(DayArgs::from_env()));
+}
+
+pub fn solve_with<S: Solution>(args: &DayArgs) {
+    log::set_level(args.level);
+    let day_run = S::INPUT.load(args.input.as_deref()).and_then(|contents| solution::run::<S>(&contents, &[1, 2]));
+    print_run(S::DAY, day_run, args.format);
+}
+
+// Prints a run of both parts in `format`, for days that run their parts themselves. Exits on an error.
+pub fn print_run(day: u32, day_run: Result<DayRun>, format: Format) {
+    match (day_run, format) {
+        (Ok(day_run), Format::Text) => {
+            println!("Parsing: {}", Elapsed(day_run.parse));
+            for (part, answer, elapsed) in day_run.parts {
+                print_answer(part, answer, elapsed);
+            }
+        }
+        (Ok(day_run), format) => print!("{}", format_results(&PartResult::from_day_run(day, day_run), format)),
+        // Machine readable output still gets a row per part, the error itself goes to stderr
+        (Err(error), format) => {
+            if format != Format::Text {
+                print!("{}", format_results(&PartResult::failed(day, &[1, 2], &error), format));
+            }
+            exit_on_error::<()>(Err(error));
+        }
+    }
+}
+
+// Prints a run made in a single pass over the input, its parse time covering the parts too. The text output only
+// has the time of the pass, the rows give it as the parse time and leave the parts at 0.
+pub fn print_stream(day: u32, day_run: Result<DayRun>, format: Format) {
+    match (day_run, format) {
+        (Ok(day_run), Format::Text) => {
+            println!("Streaming: {}", Elapsed(day_run.parse));
+            for (part, answer, _) in day_run.parts {
+                println!("P{}: {}", part, answer);
+            }
+        }
+        (day_run, format) => print_run(day, day_run, format),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    // The puzzle has no such part, like the second part of the last day
+    Unsolved,
+    // The input could not be loaded or parsed, or the solver panicked
+    Error,
+    // A stage of the day ran past the time limit
+    TimedOut,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Error => write!(f, "error"),
+            Status::TimedOut => write!(f, "timeout"),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Answer,
+    // Only set on the first part of each day, the input is parsed once for both
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+    pub status: Status,
+}
+
+impl PartResult {
+    pub fn from_day_run(day: u32, day_run: DayRun) -> Vec<PartResult> {
+        day_run.parts.into_iter().enumerate().map(|(index, (part, answer, solve))| {
+            let parse = if index == 0 { Some(day_run.parse) } else { None };
+            let status = if answer == Answer::Unsolved { Status::Unsolved } else { Status::Ok };
+            PartResult { day, part, answer, parse, solve, status }
+        }).collect()
+    }
+
+    pub fn failed(day: u32, parts: &[u8], error: &Error) -> Vec<PartResult> {
+        let status = if matches!(error, Error::TimedOut(_)) { Status::TimedOut } else { Status::Error };
+        parts.iter().map(|part| PartResult { day, part: *part, answer: Answer::Unsolved, parse: None, solve: Duration::ZERO, status }).collect()
+    }
+
+    fn failed_run(&self) -> bool {
+        matches!(self.status, Status::Error | Status::TimedOut)
+    }
+
+    // The answer as shown to the user, the status when the part failed
+    fn answer_text(&self) -> String {
+        if self.failed_run() { self.status.to_string() } else { self.answer.to_string() }
+    }
+
+    // Stable schema: day, part, answer, parse_ns, solve_ns, status.
+    // The answer is a string, null when unsolved or failed, parse_ns is only set on the first part of a day.
+    pub fn json_fields(&self) -> Vec<(&'static str, String)> {
+        let answer = if self.status == Status::Ok { json::quote(&self.answer.to_string()) } else { "null".to_string() };
+        let parse = self.parse.map_or("null".to_string(), |parse| parse.as_nanos().to_string());
+        let solve = if self.failed_run() { "null".to_string() } else { self.solve.as_nanos().to_string() };
+        vec![
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("answer", answer),
+            ("parse_ns", parse),
+            ("solve_ns", solve),
+            ("status", json::quote(&self.status.to_string())),
+        ]
+    }
+}
+
+pub fn format_table(results: &[PartResult]) -> String {
+    let width = results.iter().map(|result| result.answer_text().len()).max().unwrap_or(0).max("Answer".len());
+    let total: Duration = results.iter().map(|result| result.parse.unwrap_or_default() + result.solve).sum();
+
+    let mut res = format!("Day  Part  {:<width$}  {:>10}  {:>10}\n", "Answer", "Parse", "Solve");
+    for result in results {
+        let parse = result.parse.map(|parse| Elapsed(parse).to_string()).unwrap_or_default();
+        let solve = if result.failed_run() { String::new() } else { Elapsed(result.solve).to_string() };
+        let line = format!("{:>3}  {:>4}  {:<width$}  {:>10}  {:>10}", result.day, result.part, result.answer_text(), parse, solve);
+        res += line.trim_end();
+        res.push('\n');
+    }
+    res += &format!("{:>9}  {:<width$}  {:>22}\n", "Total", "", Elapsed(total).to_string());
+    res
+}
+
+pub fn format_json(results: &[PartResult]) -> String {
+    let rows: Vec<String> = results.iter().map(|result| json::object(&result.json_fields())).collect();
+
+    if rows.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n  {}\n]\n", rows.join(",\n  "))
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+// Same columns as the JSON output, empty fields instead of null
+pub fn format_csv(results: &[PartResult]) -> String {
+    let mut res = "day,part,answer,parse_ns,solve_ns,status\n".to_string();
+    for result in results {
+        let answer = if result.status == Status::Ok { csv_field(&result.answer.to_string()) } else { String::new() };
+        let parse = result.parse.map(|parse| parse.as_nanos().to_string()).unwrap_or_default();
+        let solve = if result.failed_run() { String::new() } else { result.solve.as_nanos().to_string() };
+        res += &format!("{},{},{},{},{},{}\n", result.day, result.part, answer, parse, solve, result.status);
+    }
+    res
+}
+
+pub fn format_results(results: &[PartResult], format: Format) -> String {
+    match format {
+        Format::Text => format_table(results),
+        Format::Json => format_json(results),
+        Format::Csv => format_csv(results),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_error() {
+        let input = "L68\nR4x\nL1";
+        let error = Error::Parse(ParseError::new(&input[5..7], "expected a number, found '4x'").locate(input));
+        assert_eq!(render_error(&error), concat!(
+            "parse error: line 2, column 2: expected a number, found '4x'\n",
+            "  |\n",
+            "2 | R4x\n",
+            "  |  ^^",
+        ));
+    }
+
+    fn results() -> Vec<PartResult> {
+        let day_run = DayRun {
+            parse: Duration::from_micros(10),
+            parts: vec![(1, Answer::Number(1573), Duration::from_micros(20)), (2, Answer::Number(15093663987272), Duration::from_micros(30))],
+        };
+        let mut results = PartResult::from_day_run(7, day_run);
+        results.extend(PartResult::from_day_run(12, DayRun { parse: Duration::from_nanos(5), parts: vec![(2, Answer::Unsolved, Duration::from_nanos(1))] }));
+        results.extend(PartResult::failed(5, &[1], &Error::Panicked));
+        results.extend(PartResult::failed(10, &[2], &Error::TimedOut(Duration::from_secs(2))));
+        results
+    }
+
+    #[test]
+    fn test_format_table() {
+        let table = format_table(&results());
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[1], "  7     1  1573                10.0µs      20.0µs");
+        assert_eq!(lines[2], "  7     2  15093663987272                  30.0µs");
+        assert_eq!(lines[3], " 12     2  -                      5ns         1ns");
+        assert_eq!(lines[4], "  5     1  error");
+        assert_eq!(lines[5], " 10     2  timeout");
+        assert_eq!(lines[6], "    Total                                  60.0µs");
+    }
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(format_json(&results()), concat!(
+            "[\n",
+            "  {\"day\": 7, \"part\": 1, \"answer\": \"1573\", \"parse_ns\": 10000, \"solve_ns\": 20000, \"status\": \"ok\"},\n",
+            "  {\"day\": 7, \"part\": 2, \"answer\": \"15093663987272\", \"parse_ns\": null, \"solve_ns\": 30000, \"status\": \"ok\"},\n",
+            "  {\"day\": 12, \"part\": 2, \"answer\": null, \"parse_ns\": 5, \"solve_ns\": 1, \"status\": \"unsolved\"},\n",
+            "  {\"day\": 5, \"part\": 1, \"answer\": null, \"parse_ns\": null, \"solve_ns\": null, \"status\": \"error\"},\n",
+            "  {\"day\": 10, \"part\": 2, \"answer\": null, \"parse_ns\": null, \"solve_ns\": null, \"status\": \"timeout\"}\n",
+            "]\n",
+        ));
+        assert_eq!(format_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(format_csv(&results()), concat!(
+            "day,part,answer,parse_ns,solve_ns,status\n",
+            "7,1,1573,10000,20000,ok\n",
+            "7,2,15093663987272,,30000,ok\n",
+            "12,2,,5,1,unsolved\n",
+            "5,1,,,,error\n",
+            "10,2,,,,timeout\n",
+        ));
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+        assert_eq!(Format::parse("csv").unwrap(), Format::Csv);
+        assert!(Format::parse("xml").is_err());
+    }
+}
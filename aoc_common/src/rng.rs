@@ -0,0 +1,72 @@
+use std::ops::RangeInclusive;
+
+// SplitMix64: tiny, fast and the same sequence for a seed on every platform, which is all the input generators need
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in 0..n, `n` must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+        let sequence: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(sequence, (0..5).map(|_| b.next_u64()).collect::<Vec<u64>>());
+        assert_ne!(Rng::new(43).next_u64(), sequence[0]);
+
+        let mut rng = Rng::new(7);
+        assert!((0..1000).map(|_| rng.range(3..=5)).all(|value| (3..=5).contains(&value)));
+        assert!((0..1000).any(|_| rng.range(3..=5) == 5));
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::input::{self, DayInput};
+use crate::rng::Rng;
+use crate::timing::timed;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+    // Puzzles without a second part, like the last day
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Answer {
+        Answer::Number(number as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(number: u64) -> Answer {
+        Answer::Number(number)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Answer {
+        Answer::Number(number as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        *self == Answer::Number(*other)
+    }
+}
+
+pub trait Solution {
+    const DAY: u32;
+    // Always `aoc_common::day_input!()`, it has to be expanded in the day's crate
+    const INPUT: DayInput;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+    // A random puzzle input that parses, `size` is the number of lines, ranges or cells that scales it
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+pub struct DayRun {
+    pub parse: Duration,
+    pub parts: Vec<(u8, Answer, Duration)>,
+}
+
+// Parses the normalized input, parse errors come back located in it
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input> {
+    parse_with(input, S::parse)
+}
+
+// The same with another parser than the day's, for the variants of an input some days take as an option
+pub fn parse_with<T>(input: &str, parse: impl FnOnce(&str) -> Result<T>) -> Result<T> {
+    let input = input::normalize(input);
+    parse(&input).map_err(|error| match error {
+        Error::Parse(error) => Error::Parse(error.locate(&input)),
+        error => error,
+    })
+}
+
+// Parses once then runs the requested parts, timing every stage separately
+pub fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<DayRun> {
+    run_with_timeout::<S>(input, parts, None)
+}
+
+// Every stage gets its own `timeout`, the first one running past it fails the day with Error::TimedOut
+pub fn run_with_timeout<S: Solution>(input: &str, parts: &[u8], timeout: Option<Duration>) -> Result<DayRun> {
+    let (parsed, parse) = timed(|| cancel::with_timeout(timeout, || parse::<S>(input)));
+    let parsed = parsed??;
+
+    let parts = parts.iter().map(|part| {
+        let (answer, elapsed) = timed(|| cancel::with_timeout(timeout, || if *part == 1 { S::part1(&parsed) } else { S::part2(&parsed) }));
+        Ok((*part, answer?, elapsed))
+    }).collect::<Result<_>>()?;
+
+    Ok(DayRun { parse, parts })
+}
+
+// The same seed and size always give the same input
+pub fn generate<S: Solution>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+// Test harness: generated inputs of a few sizes must parse and be deterministic
+pub fn check_generator<S: Solution>() {
+    for (seed, size) in [(0, 1), (1, 10), (2, 50)] {
+        let input = generate::<S>(seed, size);
+        assert_eq!(input, generate::<S>(seed, size), "generator of day {} is not deterministic", S::DAY);
+        if let Err(error) = parse::<S>(&input) {
+            panic!("generated input of day {} (seed {}, size {}): {}\n{}", S::DAY, seed, size, error, input);
+        }
+    }
+}
+
+// Property test harness: on `count` seeded inputs from `generate`, an optimised solver must agree with its
+// brute-force `reference`. The generator should keep the inputs small enough for the reference.
+pub fn check_differential<S: Solution, T: PartialEq + fmt::Debug>(
+    count: u64,
+    generate: impl Fn(&mut Rng) -> String,
+    solver: impl Fn(&S::Input) -> T,
+    reference: impl Fn(&S::Input) -> T,
+) {
+    for seed in 0..count {
+        let input = generate(&mut Rng::new(seed));
+        let parsed = parse::<S>(&input).unwrap_or_else(|error| panic!("generated input of day {} (seed {}): {}\n{}", S::DAY, seed, error, input));
+        assert_eq!(solver(&parsed), reference(&parsed), "day {}, seed {}, input:\n{}", S::DAY, seed, input);
+    }
+}
+
+// Test harness: runs every example of the day and compares the parts with an expected answer
+pub fn check_examples<S: Solution>() {
+    let examples = S::INPUT.examples().unwrap();
+    assert!(!examples.is_empty(), "no examples in {}", S::INPUT.data_path(input::EXAMPLES_DIR).display());
+
+    for example in examples {
+        let input = S::INPUT.example(example).unwrap();
+        let answers = S::INPUT.example_answers(example).unwrap();
+        let parts = answers.parts();
+        assert!(!parts.is_empty(), "example {} of day {} has no expected answer", example, S::DAY);
+
+        let day_run = run::<S>(&input, &parts).unwrap_or_else(|error| panic!("example {} of day {}: {}", example, S::DAY, error));
+        for (part, answer, _) in day_run.parts {
+            assert_eq!(Some(&answer), answers.get(part), "example {} of day {}, part {}", example, S::DAY, part);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u32 = 0;
+        const INPUT: DayInput = DayInput { name: "day_0", data_dir: "", embedded: None };
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            input.lines().map(crate::parse::number).collect()
+        }
+
+        fn part1(input: &Vec<u64>) -> Answer {
+            input.iter().sum::<u64>().into()
+        }
+
+        fn part2(input: &Vec<u64>) -> Answer {
+            input.len().into()
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let day_run = run::<Lines>("1\n2\n3", &[1, 2]).unwrap();
+        assert_eq!(day_run.parts.len(), 2);
+        assert_eq!(day_run.parts[0].1, 6);
+        assert_eq!(day_run.parts[1].1, 3);
+
+        let day_run = run::<Lines>("1\n2\n3", &[2]).unwrap();
+        assert_eq!(day_run.parts.len(), 1);
+        assert_eq!(day_run.parts[0].0, 2);
+
+        match run::<Lines>("1\nx", &[1]) {
+            Err(Error::Parse(error)) => assert_eq!((error.line, error.column), (2, 1)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_check_generator() {
+        check_generator::<Lines>();
+        let sum = |input: &Vec<u64>| Answer::Number(input.iter().sum());
+        check_differential::<Lines, Answer>(20, |rng| Lines::generate(rng, 5), Lines::part1, sum);
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(12u32).to_string(), "12");
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+// Displays a duration with a unit suited to its magnitude, e.g. "812µs" or "1.25s"
+pub struct Elapsed(pub Duration);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        if nanos < 1_000 {
+            write!(f, "{}ns", nanos)
+        } else if nanos < 1_000_000 {
+            write!(f, "{:.1}µs", nanos as f64 / 1e3)
+        } else if nanos < 1_000_000_000 {
+            write!(f, "{:.1}ms", nanos as f64 / 1e6)
+        } else {
+            write!(f, "{:.2}s", nanos as f64 / 1e9)
+        }
+    }
+}
+
+// Summary of repeated measurements of the same stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Some(Stats { median, min: sorted[0], max: sorted[n - 1] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elapsed() {
+        assert_eq!(Elapsed(Duration::from_nanos(999)).to_string(), "999ns");
+        assert_eq!(Elapsed(Duration::from_micros(812)).to_string(), "812.0µs");
+        assert_eq!(Elapsed(Duration::from_micros(2500)).to_string(), "2.5ms");
+        assert_eq!(Elapsed(Duration::from_millis(1250)).to_string(), "1.25s");
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [30, 10, 20, 50].iter().map(|micros| Duration::from_micros(*micros)).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(25));
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.max, Duration::from_micros(50));
+        assert_eq!(Stats::from_samples(&samples[..3]).unwrap().median, Duration::from_micros(20));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,879 @@
+use std::fmt;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::solution::{self, DayRun};
+use aoc_common::timing::timed;
+use aoc_common::{parse, Answer, Error, ParseError, Result, Solution};
+
+// The puzzle's dial goes from 0 to 99 and starts pointing at 50
+pub const DEFAULT_SIZE: u32 = 100;
+pub const DEFAULT_START: u32 = 50;
+
+// A dial of `size` positions, from 0 to size - 1
+#[derive(Debug, Clone)]
+pub struct Safe {
+    size: u32,
+    position: u32,
+}
+
+// Dials chained like an odometer, dial 0 first: every revolution of a dial past 0 carries a click into the next one
+#[derive(Debug, Clone)]
+pub struct Lock {
+    dials: Vec<Safe>,
+}
+
+// A turn of the dial, like 'L68'. A negative amount turns the other way, 'L-5' is 'R5'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    left: bool,
+    number: u64,
+}
+
+// A turn of one dial of a lock, like '2L68'. Without an index, like the puzzle's turns, it turns dial 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockTurn {
+    pub dial: usize,
+    pub turn: Turn,
+}
+
+// Parses turns one line at a time, see `Turn::from_reader`
+pub struct TurnReader<R> {
+    reader: R,
+    path: PathBuf,
+    line: String,
+    line_number: usize,
+    // The first of the blank lines read since the last turn, only an error if a turn follows them
+    blank_line: Option<usize>,
+}
+
+// The zeros of one dial of a lock: the turns reaching it that stopped on 0 and its clicks on 0, like the two parts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Zeros {
+    pub stops: u64,
+    pub clicks: u64,
+}
+
+// What a single turn did to the dial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub turn: Turn,
+    pub before: u32,
+    pub after: u32,
+    // Clicks that landed on 0, including the last one when the turn stops on 0
+    pub zeros: u64,
+}
+
+impl Step {
+    pub fn ends_on_zero(&self) -> bool {
+        self.after == 0
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<8} {} -> {}, {} on 0", self.turn.to_string(), self.before, self.after, self.zeros)?;
+        if self.ends_on_zero() {
+            write!(f, ", stops on 0")?;
+        }
+        Ok(())
+    }
+}
+
+// Turns the dial as it is iterated, see `Safe::steps`
+pub struct Steps<'a, I> {
+    safe: &'a mut Safe,
+    turns: I,
+}
+
+impl<I: Iterator<Item = Turn>> Iterator for Steps<'_, I> {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        let turn = self.turns.next()?;
+        let before = self.safe.position;
+        let zeros = self.safe.turn(turn);
+        Some(Step { turn, before, after: self.safe.position, zeros })
+    }
+}
+
+impl FromStr for Turn {
+    type Err = aoc_common::Error;
+
+    // A turn of a single dial, like 'L68'
+    fn from_str(s: &str) -> Result<Turn> {
+        let (dial, _) = split_dial(s);
+        if !dial.is_empty() {
+            return Err(ParseError::new(dial, "expected a turn like 'L68' or 'R48', only the dials of a lock are numbered").into());
+        }
+        let (direction, number) = s.split_at(s.chars().next().map_or(0, char::len_utf8));
+        let left = match direction {
+            "L" => true,
+            "R" => false,
+            _ => return Err(ParseError::new(direction, "expected a turn like 'L68' or 'R48'").into()),
+        };
+        match number.strip_prefix('-') {
+            Some(number) => Ok(Turn { left: !left, number: parse::number(number)? }),
+            None => Ok(Turn { left, number: parse::number(number)? }),
+        }
+    }
+}
+
+fn split_dial(s: &str) -> (&str, &str) {
+    s.split_at(s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len())
+}
+
+impl FromStr for LockTurn {
+    type Err = aoc_common::Error;
+
+    // A turn of a lock's dial like '2L68', or of dial 0 like 'L68'
+    fn from_str(s: &str) -> Result<LockTurn> {
+        let (dial, turn) = split_dial(s);
+        let dial = if dial.is_empty() { 0 } else { parse::number(dial)? };
+        Ok(LockTurn { dial, turn: turn.parse()? })
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", if self.left { 'L' } else { 'R' }, self.number)
+    }
+}
+
+impl fmt::Display for LockTurn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.dial > 0 {
+            write!(f, "{}", self.dial)?;
+        }
+        write!(f, "{}", self.turn)
+    }
+}
+
+impl From<Turn> for LockTurn {
+    fn from(turn: Turn) -> LockTurn {
+        LockTurn { dial: 0, turn }
+    }
+}
+
+impl LockTurn {
+    // One turn of a lock per line, like '2L68'
+    pub fn from_string(contents: &str) -> Result<Vec<LockTurn>> {
+        contents.lines().map(LockTurn::from_str).collect()
+    }
+}
+
+impl Turn {
+    pub fn new(left: bool, number: u64) -> Turn {
+        Turn { left, number }
+    }
+
+    // One turn per line, like 'L68'
+    pub fn from_string(contents: &str) -> Result<Vec<Turn>> {
+        contents.lines().map(Turn::from_str).collect()
+    }
+
+    // Streams the turns of `reader` in constant memory, for inputs too large to load. Same rules as the day's
+    // parser, trailing blank lines included, and parse errors point at their line of the whole input.
+    // `path` is where `reader` reads from, for its errors.
+    pub fn from_reader<R: BufRead>(reader: R, path: &Path) -> TurnReader<R> {
+        TurnReader { reader, path: path.to_path_buf(), line: String::new(), line_number: 0, blank_line: None }
+    }
+}
+
+impl<R: BufRead> Iterator for TurnReader<R> {
+    type Item = Result<Turn>;
+
+    fn next(&mut self) -> Option<Result<Turn>> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(source) => return Some(Err(Error::Io { path: self.path.clone(), source })),
+            }
+            let line = self.line.trim_end();
+            let line = if self.line_number == 1 { line.trim_start_matches('\u{feff}') } else { line };
+            if line.is_empty() {
+                self.blank_line = self.blank_line.or(Some(self.line_number));
+                continue;
+            }
+            // A blank line before this turn, the error of parsing it
+            let (line, line_number) = match self.blank_line.take() {
+                Some(blank_line) => (&line[..0], blank_line),
+                None => (line, self.line_number),
+            };
+            return Some(Turn::from_str(line).map_err(|error| match error {
+                Error::Parse(error) => {
+                    let mut error = error.locate(line);
+                    error.line = line_number;
+                    Error::Parse(error)
+                }
+                error => error,
+            }));
+        }
+    }
+}
+
+fn check_size(size: u32) -> Result<()> {
+    if size == 0 {
+        return Err(Error::Invalid("a dial needs at least 1 position".to_string()));
+    }
+    Ok(())
+}
+
+impl Safe {
+    pub fn new(size: u32, start: u32) -> Result<Safe> {
+        check_size(size)?;
+        if start >= size {
+            return Err(Error::Invalid(format!("cannot start at {} on a dial of {} positions", start, size)));
+        }
+        Ok(Safe { size, position: start })
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    // Returns how many times the dial passed or stopped on 0. Computed in u128, a position plus a turn
+    // overflows a u64 on large dials.
+    pub fn turn(&mut self, turn: Turn) -> u64 {
+        let (position, number, size) = (self.position as u128, turn.number as u128, self.size as u128);
+        if turn.left {
+            let res = if position == 0 {
+                number / size
+            } else if number >= position {
+                (number - position) / size + 1
+            } else {
+                0
+            };
+            self.position = ((position + size - number % size) % size) as u32;
+            res as u64
+        } else {
+            self.position = ((position + number) % size) as u32;
+            ((position + number) / size) as u64
+        }
+    }
+
+    // Revolutions the turn completes past 0, from size - 1 to 0 turning right or from 0 to size - 1 turning left.
+    // At most the turn's clicks.
+    pub fn carry(&self, turn: Turn) -> u64 {
+        let (position, number, size) = (self.position as u128, turn.number as u128, self.size as u128);
+        let res = if !turn.left {
+            (position + number) / size
+        } else if number > position {
+            (number - position - 1) / size + 1
+        } else {
+            0
+        };
+        res as u64
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.position == 0
+    }
+
+    // Applies the turns lazily, one step per turn
+    pub fn steps<I: IntoIterator<Item = Turn>>(&mut self, turns: I) -> Steps<'_, I::IntoIter> {
+        Steps { safe: self, turns: turns.into_iter() }
+    }
+
+    // Times the dial stopped on 0 and times it passed or stopped on 0
+    pub fn apply_turns(&mut self, turns: impl IntoIterator<Item = Turn>) -> (u64, u64) {
+        let mut nb_zeros_p2 : u64 = 0;
+        let mut nb_zeros_p1 : u64 = 0;
+        for step in self.steps(turns) {
+            nb_zeros_p2 += step.zeros;
+            if step.ends_on_zero() { nb_zeros_p1 += 1; }
+        }
+        (nb_zeros_p1, nb_zeros_p2)
+    }
+
+    // `apply_turns` on turns streamed from `reader`, stopping at the first error
+    pub fn apply_reader(&mut self, reader: impl BufRead, path: &Path) -> Result<(u64, u64)> {
+        let mut failed = Ok(());
+        let zeros = self.apply_turns(Turn::from_reader(reader, path).map_while(|turn| turn.map_err(|error| failed = Err(error)).ok()));
+        failed.map(|_| zeros)
+    }
+}
+
+impl Default for Safe {
+    fn default() -> Safe {
+        Safe { size: DEFAULT_SIZE, position: DEFAULT_START }
+    }
+}
+
+impl Lock {
+    pub fn new(dials: Vec<Safe>) -> Result<Lock> {
+        if dials.is_empty() {
+            return Err(Error::Invalid("a lock needs at least 1 dial".to_string()));
+        }
+        Ok(Lock { dials })
+    }
+
+    pub fn dials(&self) -> &[Safe] {
+        &self.dials
+    }
+
+    // Returns the zeros of every dial. The revolutions of a dial turn the next one by as many clicks in the
+    // same direction, down the chain until a dial completes none; those of the last dial are lost.
+    pub fn turn(&mut self, turn: LockTurn) -> Result<Vec<Zeros>> {
+        let mut zeros = vec![Zeros::default(); self.dials.len()];
+        self.turn_counting(turn, &mut zeros)?;
+        Ok(zeros)
+    }
+
+    fn turn_counting(&mut self, LockTurn { dial, turn }: LockTurn, zeros: &mut [Zeros]) -> Result<()> {
+        if dial >= self.dials.len() {
+            return Err(Error::Invalid(format!("turn {} targets dial {} of a lock of {} dials", LockTurn { dial, turn }, dial, self.dials.len())));
+        }
+        let mut current = turn;
+        for (dial, zeros) in self.dials[dial..].iter_mut().zip(&mut zeros[dial..]) {
+            let carry = dial.carry(current);
+            zeros.clicks += dial.turn(current);
+            if dial.is_null() {
+                zeros.stops += 1;
+            }
+            if carry == 0 {
+                break;
+            }
+            current.number = carry;
+        }
+        Ok(())
+    }
+
+    // The zeros of every dial over all the turns
+    pub fn apply_turns(&mut self, turns: impl IntoIterator<Item = LockTurn>) -> Result<Vec<Zeros>> {
+        let mut zeros = vec![Zeros::default(); self.dials.len()];
+        for turn in turns {
+            self.turn_counting(turn, &mut zeros)?;
+        }
+        Ok(zeros)
+    }
+}
+
+// What a reverse search counts: the turns stopping on 0 like part 1, or every click on 0 like part 2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCount {
+    Stops,
+    Clicks,
+}
+
+// The count of every start of a dial of `size` positions, as sorted ranges of starts sharing a count.
+// Rather than replaying the turns from each start, every turn adds a count to a range of starts: with `d` the
+// displacement before it, a turn of `n` clicks passes 0 n / size times from any start, plus once more from the
+// n % size starts s where (s + d) % size is far enough along. A sweep over those ranges takes O(t log t) for t turns,
+// whatever the size of the dial.
+pub fn counts_by_start(size: u32, turns: &[Turn], count: ZeroCount) -> Result<Vec<(RangeInclusive<u32>, u64)>> {
+    check_size(size)?;
+    let n = size as i128;
+    let mut base: u64 = 0;
+    let mut events: Vec<(u64, i64)> = vec![];
+    // Adds one to the `len` starts from `from` on, wrapping around the dial
+    let mut add = |from: i128, len: i128| {
+        let from = from.rem_euclid(n);
+        if from + len <= n {
+            events.extend([(from as u64, 1), ((from + len) as u64, -1)]);
+        } else {
+            events.extend([(from as u64, 1), (n as u64, -1), (0, 1), ((from + len - n) as u64, -1)]);
+        }
+    };
+
+    let mut displacement: i128 = 0;
+    for turn in turns {
+        let number = turn.number as i128;
+        let after = if turn.left { displacement - number } else { displacement + number };
+        match count {
+            ZeroCount::Stops => add(-after, 1),
+            ZeroCount::Clicks => {
+                // The clicks reach the positions s + a + 1 to s + a + number, counted without wrapping
+                let a = if turn.left { after - 1 } else { displacement };
+                base += (number / n) as u64;
+                if number % n > 0 {
+                    add(n - number % n - a, number % n);
+                }
+            }
+        }
+        displacement = after;
+    }
+
+    events.sort();
+    let mut res: Vec<(RangeInclusive<u32>, u64)> = vec![];
+    let (mut covered, mut start, mut index) = (0i64, 0u64, 0);
+    while start < size as u64 {
+        while index < events.len() && events[index].0 == start {
+            covered += events[index].1;
+            index += 1;
+        }
+        let end = events.get(index).map_or(size as u64, |event| event.0);
+        let value = base + covered as u64;
+        match res.last_mut() {
+            Some((range, last)) if *last == value => *range = *range.start()..=(end - 1) as u32,
+            _ => res.push((start as u32..=(end - 1) as u32, value)),
+        }
+        start = end;
+    }
+    Ok(res)
+}
+
+// Every start of a dial of `size` positions that gives `target` zeros, as sorted ranges
+pub fn starts_with(size: u32, turns: &[Turn], count: ZeroCount, target: u64) -> Result<Vec<RangeInclusive<u32>>> {
+    Ok(counts_by_start(size, turns, count)?.into_iter().filter(|(_, value)| *value == target).map(|(range, _)| range).collect())
+}
+
+// The puzzle's report on another safe
+pub fn run_safe(safe: &Safe, contents: &str) -> Result<DayRun> {
+    let (turns, parse) = timed(|| solution::parse::<Day1>(contents));
+    let turns = turns?;
+    let parts = [1, 2].map(|part| {
+        let (zeros, elapsed) = timed(|| safe.clone().apply_turns(turns.iter().copied()));
+        (part, (if part == 1 { zeros.0 } else { zeros.1 }).into(), elapsed)
+    });
+    Ok(DayRun { parse, parts: parts.into() })
+}
+
+// Every turn on another safe, one line each with the position before and after and the clicks on 0
+pub fn trace(safe: &Safe, contents: &str) -> Result<String> {
+    let turns = solution::parse::<Day1>(contents)?;
+    Ok(safe.clone().steps(turns).enumerate().map(|(index, step)| format!("{:>5}  {}\n", index + 1, step)).collect())
+}
+
+// `run_safe` on turns streamed from `reader`, parsing and both parts in one pass timed as the parse.
+// `path` is where `reader` reads from, for its errors.
+pub fn run_reader(safe: &Safe, reader: impl BufRead, path: &Path) -> Result<DayRun> {
+    let (zeros, parse) = timed(|| safe.clone().apply_reader(reader, path));
+    let zeros = zeros?;
+    Ok(DayRun { parse, parts: vec![(1, zeros.0.into(), Duration::ZERO), (2, zeros.1.into(), Duration::ZERO)] })
+}
+
+// Every start of a dial of `size` giving the target answer of each part, in place of the answer, like '37-54, 96-97'
+pub fn run_targets(size: u32, targets: &[(u8, Option<u64>)], contents: &str) -> Result<DayRun> {
+    let (turns, parse) = timed(|| solution::parse::<Day1>(contents));
+    let turns = turns?;
+    let parts = targets.iter().filter_map(|(part, target)| Some((*part, (*target)?))).map(|(part, target)| {
+        let count = if part == 1 { ZeroCount::Stops } else { ZeroCount::Clicks };
+        let (starts, elapsed) = timed(|| starts_with(size, &turns, count, target));
+        let starts: Vec<String> = starts?.iter().map(|range| {
+            if range.start() == range.end() { range.start().to_string() } else { format!("{}-{}", range.start(), range.end()) }
+        }).collect();
+        Ok((part, Answer::Text(if starts.is_empty() { "none".to_string() } else { starts.join(", ") }), elapsed))
+    });
+    Ok(DayRun { parse, parts: parts.collect::<Result<_>>()? })
+}
+
+// The zeros of every dial of `lock`, dial 0 first like '2, 1, 2': the turns stopping on 0 for part 1, the clicks
+// on 0 for part 2
+pub fn run_lock(lock: &Lock, contents: &str) -> Result<DayRun> {
+    let (turns, parse) = timed(|| solution::parse_with(contents, LockTurn::from_string));
+    let turns = turns?;
+    let parts = [1, 2].map(|part| {
+        let (zeros, elapsed) = timed(|| lock.clone().apply_turns(turns.iter().copied()));
+        let counts: Vec<String> = zeros?.iter().map(|zeros| if part == 1 { zeros.stops } else { zeros.clicks }.to_string()).collect();
+        Ok((part, Answer::Text(counts.join(", ")), elapsed))
+    });
+    Ok(DayRun { parse, parts: parts.into_iter().collect::<Result<_>>()? })
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<Turn>;
+
+    fn parse(input: &str) -> Result<Vec<Turn>> {
+        Turn::from_string(input)
+    }
+
+    fn part1(turns: &Vec<Turn>) -> Answer {
+        Safe::default().apply_turns(turns.iter().copied()).0.into()
+    }
+
+    fn part2(turns: &Vec<Turn>) -> Answer {
+        Safe::default().apply_turns(turns.iter().copied()).1.into()
+    }
+
+    // `size` turns of up to 999 clicks
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| format!("{}{}\n", if rng.chance(0.5) { 'L' } else { 'R' }, rng.range(1..=999))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day1>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day1>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day1>(2000);
+    }
+
+    #[test]
+    fn test_turn_safe() {
+        let mut safe = Safe::new(100, 50).unwrap();
+        safe.turn(Turn::new(true, 68));
+        assert_eq!(safe.position, 82);  
+        safe.turn(Turn::new(true, 30));
+        assert_eq!(safe.position, 52);
+        safe.turn(Turn::new(false, 48));
+        assert_eq!(safe.position, 0);  
+    }
+
+    // Turns the dial one click at a time, returns the new position and the number of clicks on 0
+    fn clicks(size: u64, mut position: u64, turn: Turn) -> (u64, u64) {
+        let mut zeros = 0;
+        for _ in 0..turn.number {
+            position = if turn.left { (position + size - 1) % size } else { (position + 1) % size };
+            if position == 0 {
+                zeros += 1;
+            }
+        }
+        (position, zeros)
+    }
+
+    #[test]
+    fn test_turn_against_clicks() {
+        let mut rng = Rng::new(0);
+        for _ in 0..2000 {
+            let size = match rng.below(4) {
+                0 => 1,
+                1 => rng.range(2..=10),
+                2 => rng.range(11..=1000),
+                _ => rng.range(u32::MAX as u64 - 1000..=u32::MAX as u64),
+            };
+            // Starting close to either end of a large dial, so its turns still cross 0
+            let offset = rng.below(size.min(3000));
+            let start = if rng.chance(0.5) { offset } else { size - 1 - offset };
+            let mut safe = Safe::new(size as u32, start as u32).unwrap();
+            for _ in 0..20 {
+                let number = rng.below((3 * size + 2).min(6000));
+                let turn = Turn::new(rng.chance(0.5), number);
+                let (position, zeros) = clicks(size, safe.position() as u64, turn);
+                assert_eq!(safe.turn(turn), zeros, "dial of {}, from {}, turn {}", size, start, turn);
+                assert_eq!(safe.position() as u64, position);
+            }
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let turns = Turn::from_string(&Day1::INPUT.example(1).unwrap()).unwrap();
+        let steps: Vec<Step> = Safe::default().steps(turns.iter().copied()).collect();
+        assert_eq!(steps.len(), turns.len());
+        assert_eq!(steps[0], Step { turn: Turn::new(true, 68), before: 50, after: 82, zeros: 1 });
+        assert!(steps.windows(2).all(|pair| pair[0].after == pair[1].before));
+        let totals = (steps.iter().filter(|step| step.ends_on_zero()).count() as u64, steps.iter().map(|step| step.zeros).sum::<u64>());
+        assert_eq!(totals, Safe::default().apply_turns(turns));
+
+        let mut safe = Safe::new(100, 0).unwrap();
+        let steps: Vec<Step> = safe.steps([Turn::new(true, 0), Turn::new(false, 1000)]).collect();
+        assert_eq!(steps[0].to_string(), "L0       0 -> 0, 0 on 0, stops on 0");
+        assert_eq!(steps[1].to_string(), "R1000    0 -> 0, 10 on 0, stops on 0");
+        assert_eq!(safe.position(), 0);
+    }
+
+    #[test]
+    fn test_counts_by_start() {
+        let mut rng = Rng::new(1);
+        for _ in 0..500 {
+            let size = rng.range(1..=30) as u32;
+            let nb_turns = rng.below(20);
+            let turns: Vec<Turn> = (0..nb_turns).map(|_| Turn::new(rng.chance(0.5), rng.below(100))).collect();
+            for count in [ZeroCount::Stops, ZeroCount::Clicks] {
+                let ranges = counts_by_start(size, &turns, count).unwrap();
+                assert_eq!(ranges.first().map(|(range, _)| *range.start()), Some(0));
+                assert_eq!(ranges.last().map(|(range, _)| *range.end()), Some(size - 1));
+                for (range, value) in ranges {
+                    for start in range {
+                        let zeros = Safe::new(size, start).unwrap().apply_turns(turns.iter().copied());
+                        assert_eq!(value, if count == ZeroCount::Stops { zeros.0 } else { zeros.1 }, "dial of {}, start {}", size, start);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let turns = Turn::from_string(&Day1::INPUT.example(1).unwrap()).unwrap();
+        assert!(starts_with(100, &turns, ZeroCount::Stops, 3).unwrap().iter().any(|range| range.contains(&50)));
+        assert!(starts_with(100, &turns, ZeroCount::Clicks, 6).unwrap().iter().any(|range| range.contains(&50)));
+        assert_eq!(starts_with(100, &turns, ZeroCount::Stops, 11).unwrap(), vec![]);
+        assert!(starts_with(0, &turns, ZeroCount::Stops, 0).is_err());
+
+        // A huge dial only costs the number of turns, check a few starts on the edges of the ranges
+        let ranges = counts_by_start(u32::MAX, &turns, ZeroCount::Clicks).unwrap();
+        assert!(ranges.len() <= 4 * turns.len() + 1);
+        for (range, value) in ranges {
+            for start in [*range.start(), *range.end()] {
+                assert_eq!(Safe::new(u32::MAX, start).unwrap().apply_turns(turns.iter().copied()).1, value);
+            }
+        }
+    }
+
+    // Turns a lock one click at a time, carrying each click past 0 into the next dial
+    fn lock_clicks(sizes: &[u64], positions: &mut [u64], LockTurn { dial: first, turn }: LockTurn) -> Vec<Zeros> {
+        let mut zeros = vec![Zeros::default(); sizes.len()];
+        let mut moved = vec![false; sizes.len()];
+        moved[first] = true;
+        for _ in 0..turn.number {
+            let mut dial = first;
+            while dial < sizes.len() {
+                let before = positions[dial];
+                positions[dial] = if turn.left { (before + sizes[dial] - 1) % sizes[dial] } else { (before + 1) % sizes[dial] };
+                moved[dial] = true;
+                if positions[dial] == 0 {
+                    zeros[dial].clicks += 1;
+                }
+                let wrapped = if turn.left { before == 0 } else { positions[dial] == 0 };
+                if !wrapped {
+                    break;
+                }
+                dial += 1;
+            }
+        }
+        for dial in 0..sizes.len() {
+            if moved[dial] && positions[dial] == 0 {
+                zeros[dial].stops += 1;
+            }
+        }
+        zeros
+    }
+
+    #[test]
+    fn test_lock_against_clicks() {
+        let mut rng = Rng::new(2);
+        for _ in 0..500 {
+            let sizes: Vec<u64> = (0..rng.range(1..=4)).map(|_| rng.range(1..=6)).collect();
+            let mut positions: Vec<u64> = sizes.iter().map(|&size| rng.below(size)).collect();
+            let mut lock = Lock::new(sizes.iter().zip(&positions).map(|(&size, &start)| Safe::new(size as u32, start as u32).unwrap()).collect()).unwrap();
+            for _ in 0..20 {
+                let turn = LockTurn { dial: rng.below(sizes.len() as u64) as usize, turn: Turn::new(rng.chance(0.5), rng.below(100)) };
+                let zeros = lock_clicks(&sizes, &mut positions, turn);
+                assert_eq!(lock.turn(turn).unwrap(), zeros, "lock of {:?}, turn {}", sizes, turn);
+                assert_eq!(lock.dials().iter().map(|dial| dial.position() as u64).collect::<Vec<u64>>(), positions);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lock() {
+        // A single dial is the puzzle's safe
+        let turns = Turn::from_string(&Day1::INPUT.example(1).unwrap()).unwrap();
+        let zeros = Lock::new(vec![Safe::default()]).unwrap().apply_turns(turns.iter().copied().map(LockTurn::from)).unwrap();
+        assert_eq!(zeros, vec![Zeros { stops: 3, clicks: 6 }]);
+
+        // 95 + 10 carries one click, 0 - 1 borrows one back through both dials
+        let mut lock = Lock::new(vec![Safe::new(100, 95).unwrap(), Safe::new(10, 9).unwrap(), Safe::new(10, 0).unwrap()]).unwrap();
+        assert_eq!(lock.turn(Turn::new(false, 10).into()).unwrap(), vec![Zeros { stops: 0, clicks: 1 }, Zeros { stops: 1, clicks: 1 }, Zeros { stops: 0, clicks: 0 }]);
+        assert_eq!(lock.dials().iter().map(Safe::position).collect::<Vec<u32>>(), vec![5, 0, 1]);
+        assert_eq!(lock.turn(LockTurn::from_str("1L1").unwrap()).unwrap(), vec![Zeros::default(), Zeros::default(), Zeros { stops: 1, clicks: 1 }]);
+        assert_eq!(lock.dials().iter().map(Safe::position).collect::<Vec<u32>>(), vec![5, 9, 0]);
+
+        assert!(lock.turn(LockTurn { dial: 3, turn: Turn::new(true, 1) }).is_err());
+        assert!(Lock::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_runs() {
+        let contents = Day1::INPUT.example(1).unwrap();
+        let answers = |day_run: DayRun| day_run.parts.into_iter().map(|(part, answer, _)| format!("{}: {}", part, answer)).collect::<Vec<String>>();
+        assert_eq!(answers(run_safe(&Safe::default(), &contents).unwrap()), ["1: 3", "2: 6"]);
+        assert_eq!(answers(run_reader(&Safe::default(), contents.as_bytes(), Path::new("<example>")).unwrap()), ["1: 3", "2: 6"]);
+        assert_eq!(answers(run_lock(&Lock::new(vec![Safe::default()]).unwrap(), &contents).unwrap()), ["1: 3", "2: 6"]);
+        assert_eq!(answers(run_targets(100, &[(1, None), (2, Some(100))], &contents).unwrap()), ["2: none"]);
+        assert!(answers(run_targets(100, &[(1, Some(3))], &contents).unwrap())[0].contains("50"));
+
+        let trace = trace(&Safe::default(), &contents).unwrap();
+        assert_eq!(trace.lines().count(), contents.lines().count());
+        assert_eq!(trace.lines().next(), Some("    1  L68      50 -> 82, 1 on 0"));
+        assert!(run_safe(&Safe::default(), "L5\nX1\n").is_err());
+        assert!(run_lock(&Lock::new(vec![Safe::default()]).unwrap(), "1L5\n").is_err());
+    }
+
+    #[test]
+    fn test_dial_sizes() {
+        let mut safe = Safe::new(1, 0).unwrap();
+        assert_eq!(safe.apply_turns([Turn::new(true, 5), Turn::new(false, 3)]), (2, 8));
+
+        let mut safe = Safe::new(u32::MAX, u32::MAX - 1).unwrap();
+        assert_eq!(safe.turn(Turn::new(false, u32::MAX as u64)), 1);
+        assert_eq!(safe.position(), u32::MAX - 1);
+        assert_eq!(safe.turn(Turn::new(true, u32::MAX as u64 - 1)), 1);
+        assert_eq!(safe.position(), 0);
+
+        // 64-bit turns on a 32-bit dial, u64::MAX is a multiple of u32::MAX so both come back to 1
+        let mut safe = Safe::new(u32::MAX, 1).unwrap();
+        assert_eq!(safe.turn(Turn::new(false, u64::MAX)), u64::MAX / u32::MAX as u64);
+        assert_eq!(safe.position(), 1);
+        assert_eq!(safe.turn(Turn::new(true, u64::MAX)), u64::MAX / u32::MAX as u64);
+        assert_eq!(safe.position(), 1);
+
+        assert!(Safe::new(0, 0).is_err());
+        assert!(Safe::new(10, 10).is_err());
+        assert_eq!((Safe::default().size(), Safe::default().position()), (DEFAULT_SIZE, DEFAULT_START));
+    }
+
+    #[test]
+    fn test_turn_from_str() {
+        let turn1 = Turn::from_str("L68").unwrap();
+        assert!(turn1.left);
+        assert_eq!(turn1.number, 68);
+
+        let turn1 = Turn::from_str("R208").unwrap();
+        assert!(!turn1.left);
+        assert_eq!(turn1.number, 208);
+    }
+
+    #[test]
+    fn test_turn_syntax() {
+        let turn1 = LockTurn::from_str("2L68").unwrap();
+        assert_eq!(turn1, LockTurn { dial: 2, turn: Turn::new(true, 68) });
+        assert_eq!(turn1.to_string(), "2L68");
+        assert_eq!(LockTurn::from_str("0R5").unwrap(), Turn::new(false, 5).into());
+        assert_eq!(LockTurn::from_str("L68").unwrap(), Turn::from_str("L68").unwrap().into());
+        assert!(Turn::from_str("2L68").is_err());
+        assert!(Day1::parse("L5\n1R3\n").is_err());
+
+        assert_eq!(Turn::from_str("L-5").unwrap(), Turn::new(false, 5));
+        assert_eq!(Turn::from_str("R+5").unwrap(), Turn::new(false, 5));
+        assert_eq!(Turn::from_str("R18446744073709551615").unwrap(), Turn::new(false, u64::MAX));
+        assert_eq!(Turn::from_str("L-18446744073709551615").unwrap(), Turn::new(false, u64::MAX));
+
+        assert!(Turn::from_str("X12").is_err());
+        assert!(LockTurn::from_str("2X12").is_err());
+        assert!(Turn::from_str("L--5").is_err());
+        assert!(Turn::from_str("R18446744073709551616").is_err());
+        assert!(LockTurn::from_str("2").is_err());
+        assert!(Turn::from_str("L").is_err());
+        assert!(Turn::from_str("").is_err());
+    }
+
+    // Both parsers on `input`: the turns, or the line, column and message of the parse error
+    fn parse_both(input: &str) -> [std::result::Result<Vec<Turn>, (usize, usize, String)>; 2] {
+        let location = |error| match error {
+            Error::Parse(error) => (error.line, error.column, error.message),
+            error => panic!("expected a parse error, got {:?}", error),
+        };
+        [
+            solution::parse::<Day1>(input).map_err(location),
+            Turn::from_reader(input.as_bytes(), Path::new("input.txt")).collect::<Result<Vec<Turn>>>().map_err(location),
+        ]
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let example = Day1::INPUT.example(1).unwrap();
+        let turns: Vec<Turn> = Turn::from_reader(example.as_bytes(), Path::new("1.txt")).collect::<Result<_>>().unwrap();
+        assert_eq!(turns, Turn::from_string(&example).unwrap());
+        assert_eq!(Safe::default().apply_reader(example.as_bytes(), Path::new("1.txt")).unwrap(), Safe::default().apply_turns(turns));
+
+        let [parsed, streamed] = parse_both("\u{feff}L5\r\nR-3  \n\n \n");
+        assert_eq!(parsed, Ok(vec![Turn::new(true, 5), Turn::new(true, 3)]));
+        assert_eq!(streamed, parsed);
+        let [parsed, streamed] = parse_both("L5\n\nR3\n");
+        assert_eq!(parsed.as_ref().unwrap_err().0, 2);
+        assert_eq!(streamed, parsed);
+        let [parsed, streamed] = parse_both("L5\nR5\nR4x\nL1\n");
+        assert_eq!(parsed.as_ref().unwrap_err().0, 3);
+        assert_eq!(streamed, parsed);
+
+        // The same rules on any input
+        let mut rng = Rng::new(3);
+        for _ in 0..2000 {
+            let size = rng.range(1..=10) as usize;
+            let generated = Day1::generate(&mut rng, size);
+            let input = fuzz::mutate(&mut rng, &generated);
+            if let Ok(input) = std::str::from_utf8(&input) {
+                let [parsed, streamed] = parse_both(input);
+                assert_eq!(streamed, parsed, "input {:?}", input);
+            }
+        }
+
+        match Safe::default().apply_reader(&[b'L', 0xff, b'\n'][..], Path::new("huge.txt")) {
+            Err(Error::Io { path, .. }) => assert_eq!(path, Path::new("huge.txt")),
+            result => panic!("expected a read error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_apply_turns() {
+        {
+            let mut safe = Safe::new(100, 50).unwrap();
+            let turns = vec![
+                Turn::from_str("L68").unwrap(),
+                Turn::from_str("L30").unwrap(),
+                Turn::from_str("R48").unwrap(),
+                Turn::from_str("L5").unwrap(),
+                Turn::from_str("R60").unwrap(),
+                Turn::from_str("L55").unwrap(),
+                Turn::from_str("L1").unwrap(),
+                Turn::from_str("L99").unwrap(),
+                Turn::from_str("R14").unwrap(),
+                Turn::from_str("L82").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (3, 6));
+        }
+        {
+            let mut safe = Safe::new(100, 99).unwrap();
+            let turns = vec![
+                Turn::from_str("R1000").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (0, 10));
+        }
+        {
+            let mut safe = Safe::new(100, 0).unwrap();
+            let turns = vec![
+                Turn::from_str("R1000").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (1, 10));
+        }
+        {
+            let mut safe = Safe::new(100, 99).unwrap();
+            let turns = vec![
+                Turn::from_str("L1000").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (0, 10));
+        }
+        {
+            let mut safe = Safe::new(100, 0).unwrap();
+            let turns = vec![
+                Turn::from_str("L1000").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (1, 10));
+        }
+        {
+            let mut safe = Safe::new(100, 0).unwrap();
+            let turns = vec![
+                Turn::from_str("L0").unwrap(),
+                ];
+            let nb_zeros = safe.apply_turns(turns);
+            assert_eq!(nb_zeros, (1, 0));
+        }
+    }
+
+}
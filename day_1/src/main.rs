@@ -1,168 +1,86 @@
-use std::fs;
 use std::env;
 
-static MAX_POSITION: u32 = 100;
-struct Safe {
-    position: u32,
+use aoc_common::args::{self, DayArgs};
+use aoc_common::report::Format;
+use aoc_common::{log, report};
+use aoc_common::{Error, Solution};
+use day_1::{Day1, Lock, Safe, DEFAULT_SIZE, DEFAULT_START};
+
+// `option` ignores the others, giving one of them with it is a mistake
+fn check_alone(option: &str, others: &[(&str, bool)]) -> aoc_common::Result<()> {
+    match others.iter().find(|(_, given)| *given) {
+        Some((other, _)) => Err(Error::Usage(format!("'{}' cannot be combined with '{}'", option, other))),
+        None => Ok(()),
+    }
 }
 
-struct Turn {
-    left: bool,
-    number: u32,
+// `--lock 100:50,10,10` chains dials of 100, 10 and 10 positions, starting at 50, 0 and 0
+fn parse_lock(spec: &str) -> aoc_common::Result<Lock> {
+    let invalid = || Error::Usage(format!("invalid lock '{}', expected dials like '100:50,10'", spec));
+    let dials = spec.split(',').map(|dial| {
+        let (size, start) = dial.split_once(':').unwrap_or((dial, "0"));
+        Safe::new(size.parse().map_err(|_| invalid())?, start.parse().map_err(|_| invalid())?)
+    });
+    Lock::new(dials.collect::<aoc_common::Result<Vec<Safe>>>()?)
 }
 
-impl Turn {
-    fn from_str(s: &str) -> Turn {
-        let left = s.get(0..1) == Some("L");
-        let number: u32 = s.get(1..).unwrap_or("0").parse().unwrap();
-        Turn { left, number : number}
-    }
-
-    fn from_file(path: &str) -> Vec<Turn> {
-        let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
-        contents.lines().map(|line| Turn::from_str(line)).collect()
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    // `--dial-size <n>` and `--start <n>` open another safe than the puzzle's
+    let size = report::exit_on_error(args::take_value(&mut args, "--dial-size"));
+    let start = report::exit_on_error(args::take_value(&mut args, "--start"));
+    let trace = args::take_flag(&mut args, "--trace");
+    let stream = args::take_flag(&mut args, "--stream");
+    // `--target-p1 <n>` and `--target-p2 <n>` look for the starts giving that answer instead
+    let targets = [
+        (1, report::exit_on_error(args::take_value::<u64>(&mut args, "--target-p1"))),
+        (2, report::exit_on_error(args::take_value::<u64>(&mut args, "--target-p2"))),
+    ];
+    let lock = report::exit_on_error(args::take_value::<String>(&mut args, "--lock"));
+    let day_args = report::exit_on_error(DayArgs::parse(&args));
+    log::set_level(day_args.level);
+    // The trace is for reading, it would break the rows of json or csv
+    if trace && day_args.format != Format::Text {
+        report::exit_on_error::<()>(Err(Error::Usage("'--trace' only works with '--format text'".to_string())));
     }
-}
-
-
-impl Safe {
-    fn turn(&mut self, turn: Turn) -> u32 {
-        if turn.left {
-            if self.position <= turn.number {
-                let mut res = (turn.number - self.position) / MAX_POSITION + 1;
-                if self.position == 0 {
-                    res -= 1;
-                    
-                }
-                self.position = MAX_POSITION - (turn.number - self.position) % MAX_POSITION;
-                self.position = self.position % MAX_POSITION;
-                res
-                
-            }else{
-                self.position -= turn.number;
-                0
-            }
-        } else {
-            self.position += turn.number;
-            let res = self.position / MAX_POSITION;
-            self.position %= MAX_POSITION;
-            res
-        }
+    if let Some(lock) = lock {
+        let others = [
+            ("--dial-size", size.is_some()),
+            ("--start", start.is_some()),
+            ("--trace", trace),
+            ("--stream", stream),
+            ("--target-p1", targets[0].1.is_some()),
+            ("--target-p2", targets[1].1.is_some()),
+        ];
+        report::exit_on_error(check_alone("--lock", &others));
+        let lock = report::exit_on_error(parse_lock(&lock));
+        let day_run = Day1::INPUT.load(day_args.input.as_deref()).and_then(|contents| day_1::run_lock(&lock, &contents));
+        return report::print_run(Day1::DAY, day_run, day_args.format);
     }
-
-    fn is_null(&self) -> bool {
-        self.position == 0
+    if let Some((part, _)) = targets.iter().find(|(_, target)| target.is_some()) {
+        let option = format!("--target-p{}", part);
+        report::exit_on_error(check_alone(&option, &[("--start", start.is_some()), ("--trace", trace), ("--stream", stream)]));
+        let size = size.unwrap_or(DEFAULT_SIZE);
+        let day_run = Day1::INPUT.load(day_args.input.as_deref()).and_then(|contents| day_1::run_targets(size, &targets, &contents));
+        return report::print_run(Day1::DAY, day_run, day_args.format);
     }
-
-    fn apply_turns(&mut self, turns: Vec<Turn>) -> (u32, u32) {
-        let mut nb_zeros_p2 : u32 = 0;
-        let mut nb_zeros_p1 : u32 = 0;
-        for turn in turns {
-            nb_zeros_p2 += self.turn(turn);
-            if self.is_null() { nb_zeros_p1 += 1; }
-        }
-        (nb_zeros_p1, nb_zeros_p2)
+    if stream {
+        report::exit_on_error(check_alone("--stream", &[("--trace", trace)]));
+        let safe = report::exit_on_error(Safe::new(size.unwrap_or(DEFAULT_SIZE), start.unwrap_or(DEFAULT_START)));
+        // The turns are fed to the safe as they are read, for inputs too large to load
+        let day_run = Day1::INPUT.open(day_args.input.as_deref()).and_then(|(path, reader)| day_1::run_reader(&safe, reader, &path));
+        return report::print_stream(Day1::DAY, day_run, day_args.format);
     }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_turn_safe() {
-        let mut safe = Safe { position: 50 };
-        safe.turn(Turn { left: true, number: 68 });
-        assert_eq!(safe.position, 82);  
-        safe.turn(Turn { left: true, number: 30 });
-        assert_eq!(safe.position, 52);
-        safe.turn(Turn { left: false, number: 48 });
-        assert_eq!(safe.position, 0);  
+    if size.is_none() && start.is_none() && !trace {
+        return report::solve_with::<Day1>(&day_args);
     }
 
-    #[test]
-    fn test_turn_from_str() {
-        let turn1 = Turn::from_str("L68");
-        assert_eq!(turn1.left, true);
-        assert_eq!(turn1.number, 68);
-
-        let turn1 = Turn::from_str("R208");
-        assert_eq!(turn1.left, false);
-        assert_eq!(turn1.number, 8);
-    }
-
-    #[test]
-    fn test_apply_turns() {
-        {
-            let mut safe = Safe { position: 50 };
-            let turns = vec![
-                Turn::from_str("L68"),
-                Turn::from_str("L30"),
-                Turn::from_str("R48"),
-                Turn::from_str("L5"),
-                Turn::from_str("R60"),
-                Turn::from_str("L55"),
-                Turn::from_str("L1"),
-                Turn::from_str("L99"),
-                Turn::from_str("R14"),
-                Turn::from_str("L82"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (3, 6));
-        }
-        {
-            let mut safe = Safe { position: 99 };
-            let turns = vec![
-                Turn::from_str("R1000"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (0, 10));
-        }
-        {
-            let mut safe = Safe { position: 0 };
-            let turns = vec![
-                Turn::from_str("R1000"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (1, 10));
-        }
-        {
-            let mut safe = Safe { position: 99 };
-            let turns = vec![
-                Turn::from_str("L1000"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (0, 10));
-        }
-        {
-            let mut safe = Safe { position: 0 };
-            let turns = vec![
-                Turn::from_str("L1000"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (1, 10));
+    let safe = report::exit_on_error(Safe::new(size.unwrap_or(DEFAULT_SIZE), start.unwrap_or(DEFAULT_START)));
+    let day_run = Day1::INPUT.load(day_args.input.as_deref()).and_then(|contents| {
+        if trace {
+            print!("{}", day_1::trace(&safe, &contents)?);
         }
-        {
-            let mut safe = Safe { position: 0 };
-            let turns = vec![
-                Turn::from_str("L0"),
-                ];
-            let nb_zeros = safe.apply_turns(turns);
-            assert_eq!(nb_zeros, (1, 0));
-        }
-    }
-
+        day_1::run_safe(&safe, &contents)
+    });
+    report::print_run(Day1::DAY, day_run, day_args.format);
 }
-
-fn get_file_path(file: &str) -> std::path::PathBuf {
-    let exe_path = env::current_exe().expect("Failed to get exe path");
-    exe_path.parent().unwrap().join(file)
-}
-
-fn main() { 
-    let turns = Turn::from_file(get_file_path("input.txt").to_str().unwrap());
-    let mut safe = Safe { position: 50 };
-    let nb_zeros = safe.apply_turns(turns);
-    println!("P1 = {}, P2 = {}", nb_zeros.0, nb_zeros.1);
-}
\ No newline at end of file
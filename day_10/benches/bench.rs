@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::bench::bench_main::<day_10::Day10>();
+}
@@ -0,0 +1,466 @@
+use std::collections::HashSet;
+use std::collections::HashMap;
+
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{cancel, parse, Answer, ParseError, Result, Solution};
+
+struct MachineParsed {
+    lights : Vec<bool>,
+    buttons : Vec<Vec<bool>>,
+    joltage: Vec<u32>,
+}
+
+// Lights and button sets are packed into u32 bit masks
+const MAX_LIGHTS: usize = 32;
+
+fn delimited(token: &str, open: char, close: char) -> Result<&str> {
+    token.strip_prefix(open).and_then(|inner| inner.strip_suffix(close))
+        .ok_or_else(|| ParseError::new(token, format!("expected '{}...{}'", open, close)).into())
+}
+
+impl MachineParsed {
+    fn from_line(line : &str) -> Result<MachineParsed>{
+        let parts = line.split_whitespace().collect::<Vec<&str>>();
+        if parts.len() < 2 {
+            return Err(ParseError::new(line, "expected a machine like '[.##.] (3) (1,3) {3,5,4,7}'").into());
+        }
+
+        let lights_str = delimited(parts[0], '[', ']')?;
+        if let Some((i, c)) = lights_str.char_indices().find(|(_, c)| *c != '#' && *c != '.') {
+            return Err(ParseError::new(&lights_str[i..i + c.len_utf8()], format!("expected '#' or '.', found '{}'", c)).into());
+        }
+        let nb_lights = lights_str.len();
+        if nb_lights == 0 || nb_lights > MAX_LIGHTS {
+            return Err(ParseError::new(parts[0], format!("expected between 1 and {} lights", MAX_LIGHTS)).into());
+        }
+
+        let button_parts = &parts[1..(parts.len()-1)];
+        if button_parts.len() >= MAX_LIGHTS {
+            return Err(ParseError::new(line, format!("expected fewer than {} buttons", MAX_LIGHTS)).into());
+        }
+        let mut buttons = vec![];
+        for button_str in button_parts {
+            let mut button = vec![false; nb_lights];
+            for input in delimited(button_str, '(', ')')?.split(',') {
+                let index = parse::number::<usize>(input)?;
+                if index >= nb_lights {
+                    return Err(ParseError::new(input, format!("light {} is out of range, the machine has {} lights", index, nb_lights)).into());
+                }
+                button[index] = true;
+            }
+            buttons.push(button);
+        }
+
+        let joltage_str = parts.last().unwrap();
+        let joltage = delimited(joltage_str, '{', '}')?.split(',').map(parse::number).collect::<Result<Vec<u32>>>()?;
+        if joltage.len() != nb_lights {
+            return Err(ParseError::new(joltage_str, format!("expected {} joltages, found {}", nb_lights, joltage.len())).into());
+        }
+
+        Ok(MachineParsed{
+            lights : lights_str.chars().map(|light|{light == '#'}).collect(),
+            buttons,
+            joltage,
+        })
+    }
+
+    fn from_string(string : &str) -> Result<Vec<MachineParsed>>{
+        string.lines().map(MachineParsed::from_line).collect()
+    }
+}
+
+fn compress(vec : &[bool]) -> u32{
+    vec.iter().fold(0, |res, digit| {res * 2 + if *digit {1} else {0}})
+}
+
+fn uncompress(bits : u32, n : usize) -> Vec<bool>{
+    (0..n).rev().map(|digit| {bits & (1 << digit) > 0 }).collect()
+}
+
+impl Machine {    
+    // Fewest button presses to turn the lights on
+    pub fn find_min_nb_press(&self) -> u32{
+        let mut min_press = u32::MAX;
+
+        for i in 0..(1<<self.buttons.len()){
+            if self.press(i) == self.lights{
+                min_press = min_press.min(i.count_ones());
+            }
+        }
+        min_press
+    }
+}
+
+pub fn solve_p1(machines : &[Machine]) -> u32{
+    machines.iter().fold(0, |res, machine| res + machine.find_min_nb_press())
+}
+
+pub struct Machine {
+    buttons : Vec<Vec<u32>>,
+    buttons_encoded : Vec<u32>,
+    joltages : Vec<u32>,
+    lights : u32,
+}
+
+impl Machine {
+    fn from_mp(parsed : &MachineParsed) -> Machine{
+        Machine { 
+            lights: compress(&parsed.lights),
+            joltages: parsed.joltage.clone(), 
+            buttons: parsed.buttons.iter().map(|vec: &Vec<bool>| vec.iter().map(|bit| if *bit {1} else {0}).collect()).collect(),
+            buttons_encoded: parsed.buttons.iter().map(|vec: &Vec<bool>| compress(vec)).collect()
+        }
+    }
+
+    fn reduce(&self, new_joltages : &[u32]) -> Machine{
+        Machine { 
+            lights: self.lights,
+            joltages: new_joltages.to_vec(), 
+            buttons: self.buttons.clone(),
+            buttons_encoded: self.buttons_encoded.clone(),
+        }
+    }
+
+    fn from_mps(parsed : &[MachineParsed]) -> Vec<Machine>{
+        parsed.iter().map(|parsed| {Machine::from_mp(parsed)}).collect()
+    }
+
+    // One machine per line, like '[.##.] (3) (1,3) {3,5,4,7}'
+    pub fn from_string(string : &str) -> Result<Vec<Machine>>{
+        Ok(Machine::from_mps(&MachineParsed::from_string(string)?))
+    }
+
+    //return new state after pushing buttons
+    fn press(&self, index : u32) -> u32{
+        let mut res : u32 = 0;
+        for i in 0..self.buttons_encoded.len(){
+            if (index & (1 << i)) != 0 {
+                res ^= self.buttons_encoded[i];
+            }
+        }
+        res
+    }
+
+    fn press_joltages(&self, index : u32) -> Option<Vec<u32>>{
+        let mut res  = self.joltages.clone();
+        for i in 0..self.buttons_encoded.len(){
+            if (index & (1 << i)) != 0 {
+                let decompressed = uncompress(self.buttons_encoded[i], res.len());
+                for j in 0..res.len(){
+                    if res[j] == 0 && decompressed[j] {return None;}
+                    res[j] -= if decompressed[j] {1} else {0};
+                }
+            }
+        }
+        Some(res)
+    }
+
+    // Fewest button presses to reach the joltages: presses of the buttons making every joltage even, then
+    // the same machine with the halved joltages
+    pub fn i_have_cheated_p2(&self) -> u32{
+        let n = self.buttons.len();
+
+        // create inputs possible
+        let mut hash_possible : HashMap<u32, Vec<u32>> = HashMap::new();
+        for i in 0..(1<<n){
+            hash_possible.entry(self.press(i)).or_insert(vec![]).push(i);
+        }
+
+        self.solve_reduce(&hash_possible).unwrap()
+    }
+
+    fn solve_reduce(&self, hash_possible : &HashMap<u32, Vec<u32>>) -> Option<u32>{
+        if self.joltages.iter().all(|x| *x == 0){Some(0)}
+        else{
+            let compressed_to_even = compress(&self.joltages.iter().map(|nb|{nb % 2 == 1}).collect::<Vec<bool>>());
+            let eveners = hash_possible.get(&compressed_to_even)?;
+
+            let mut min_nb = None;
+            for evener in eveners{
+                if let Some(pressed) = self.press_joltages(*evener) {
+                    match self.reduce(&pressed.iter().map(|nb| { nb >> 1}).collect::<Vec<u32>>()).solve_reduce(hash_possible){
+                        None => (),
+                        Some(value) => {min_nb = Some(min_nb.unwrap_or(u32::MAX).min(2*value+evener.count_ones()));}
+                    }
+                }
+            }
+            min_nb
+        }
+    }
+
+}
+
+// A machine solvable by construction: the lights are a combination of the buttons and the joltages
+// are the counters after pressing every button less than `max_presses` times
+fn generate_machine(rng : &mut Rng, max_lights : u64, max_presses : u64) -> String {
+    let nb_lights = rng.range(3..=max_lights) as usize;
+    let mut buttons: Vec<Vec<usize>> = (0..rng.range(nb_lights as u64 / 2 + 1..=nb_lights as u64 + 3)).map(|_| {
+        let mut button: Vec<usize> = (0..nb_lights).filter(|_| rng.chance(0.3)).collect();
+        if button.is_empty() {
+            button.push(rng.below(nb_lights as u64) as usize);
+        }
+        button
+    }).collect();
+    for light in 0..nb_lights {
+        if !buttons.iter().any(|button| button.contains(&light)) {
+            let index = rng.below(buttons.len() as u64) as usize;
+            buttons[index].push(light);
+            buttons[index].sort();
+        }
+    }
+
+    let mut lights = vec![false; nb_lights];
+    let mut joltages = vec![0; nb_lights];
+    for button in &buttons {
+        let presses = rng.below(max_presses);
+        for light in button {
+            lights[*light] ^= presses % 2 == 1;
+            joltages[*light] += presses;
+        }
+    }
+
+    let lights: String = lights.iter().map(|on| if *on { '#' } else { '.' }).collect();
+    let buttons: Vec<String> = buttons.iter().map(|button| format!("({})", button.iter().map(|light| light.to_string()).collect::<Vec<String>>().join(","))).collect();
+    let joltages: Vec<String> = joltages.iter().map(|joltage| joltage.to_string()).collect();
+    format!("[{}] {} {{{}}}", lights, buttons.join(" "), joltages.join(","))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>> {
+        Machine::from_string(input)
+    }
+
+    fn part1(machines: &Vec<Machine>) -> Answer {
+        solve_p1(machines).into()
+    }
+
+    fn part2(machines: &Vec<Machine>) -> Answer {
+        machines.iter().fold(0, |res, machine|{ res + machine.i_have_cheated_p2()}).into()
+    }
+
+    // `size` machines of 3 to 10 lights
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| generate_machine(rng, 10, 20) + "\n").collect()
+    }
+}
+
+// End here
+
+
+
+
+
+
+
+
+// Non working attempt using weighted A* 
+// Only work for small inputs (40ish^6 max) 
+
+#[allow(dead_code)]
+impl Machine {
+    fn get_max_dp(&self)-> f64{
+        self.buttons.iter().max_by(|a, b| a.iter().sum::<u32>().cmp(&b.iter().sum::<u32>())).unwrap().iter().sum::<u32>() as f64
+    }
+
+    fn get_mean_dp(&self)-> f64{
+        self.buttons.iter().fold(0.0f64, |res, butto| res + butto.iter().sum::<u32>() as f64) / self.buttons.len() as f64
+    }
+
+    fn solve_from_0(&self, h : fn(&[u32], &[u32]) -> f64, n_max : u32) ->u32{
+        self.solve_from(0, h, n_max)
+    }
+
+    fn solve_from(&self, start : u32, h : fn(&[u32], &[u32]) -> f64, n_max : u32) ->u32{
+        self.solve_a_star(&vec![start; self.joltages.len()], h, n_max)
+    }
+
+    fn solve_from_percent(&self, percent : u32, h : fn(&[u32], &[u32]) -> f64, n_max : u32) ->u32{
+        self.solve_a_star(&self.joltages.iter().map(|nb|{nb*percent/100}).collect::<Vec<u32>>(), h, n_max)
+    }
+
+    fn get_simpler(&self, percent : u32) -> Machine{
+        Machine { lights: self.lights, buttons_encoded : self.buttons_encoded.clone(), buttons: self.buttons.clone(), joltages: self.joltages.iter().map(|nb|{nb*percent/100}).collect() }
+    }
+
+    fn solve_a_star(&self, start : &[u32], h : fn(&[u32], &[u32]) -> f64, n_max : u32) -> u32{
+        let mut open = HashSet::from([start.to_vec()]);
+
+        let mut gscore = HashMap::from([(start.to_vec(), 0)]);
+        let mut fscore = HashMap::from([(start.to_vec(), h(start, &self.joltages))]);
+
+        let mut nb_iter = 0;
+
+        while !open.is_empty(){
+            nb_iter += 1;
+            let current = open.iter().min_by(|a, b| fscore.get(*a).unwrap_or(&f64::INFINITY).partial_cmp(fscore.get(*b).unwrap_or(&f64::INFINITY)).unwrap()).unwrap().clone();
+            open.remove(&current);
+
+            if (0..current.len()).any(|index|{current[index] > self.joltages[index]}){
+                continue;
+            }
+
+            if *current == self.joltages {return gscore[&current];}
+            if nb_iter > n_max || cancel::cancelled() {
+                return 0;
+            }
+            aoc_common::trace!("state {:?}, distance {}", current, norme_1(&current, &self.joltages));
+
+            let new_score = gscore[&current] + 1;
+
+            let max_index = diff_argmax(&current, &self.joltages);
+            for button in self.buttons.iter(){
+                if button[max_index] > 0 {
+                    let new_state: Vec<u32> = current.iter().enumerate().map(|(index, nb)|{nb + button[index]}).collect();
+                    
+                    if new_score < *gscore.get(&new_state).unwrap_or(&u32::MAX){
+                        *gscore.entry(new_state.clone()).or_insert(0) = new_score;
+                        *fscore.entry(new_state.clone()).or_insert(0.0) = new_score as f64 + h(&new_state, &self.joltages);
+                        open.insert(new_state);
+                    }
+                }
+            }
+        }
+        0
+    }
+
+    fn solve_new(&self) -> u32{
+        let first_res = self.solve_from_0(norme_direction, 300000);
+        if first_res > 0 {
+            first_res
+        }else{
+            let res_20 = self.get_simpler(25).solve_from_0(norme_direction, u32::MAX);
+            if res_20 == 0 {aoc_common::warn!("no path found"); 0}
+            else{
+                res_20*3 + self.solve_from_percent(75,norme_direction, u32::MAX)
+            }
+        }
+    }
+
+}
+
+
+#[allow(dead_code)]
+fn n2(a: &[u32]) -> f64{
+    a.iter().fold(0.0, |res, val|{res + (val * val) as f64}).sqrt()
+}
+
+#[allow(dead_code)]
+fn diff_argmax(a: &[u32], b: &[u32]) -> usize{
+    (0..a.len()).fold((0, 0u32), |res, index|{
+        let new_res = a[index].abs_diff(b[index]);
+        if new_res > res.1 {(index, new_res)} else {res}
+    }).0
+}
+
+#[allow(dead_code)]
+fn norme_1(a: &[u32], b: &[u32]) -> f64 {
+    (0..a.len()).fold(0, |res, index|{res + a[index].abs_diff(b[index])}) as f64
+}
+
+#[allow(dead_code)]
+fn norme_2(a: &[u32], b: &[u32]) -> f64 {
+    (0..a.len()).fold(0.0, |res, index|{res + (a[index] as f64 -b[index] as f64)*(a[index] as f64 -b[index] as f64)}).sqrt()
+}
+
+#[allow(dead_code)]
+fn dot(a: &[u32], b: &[u32]) -> f64 {
+    (0..a.len()).fold(0, |res, index|{res + a[index] * b[index]}) as f64
+}
+
+#[allow(dead_code)]
+fn norme_direction(a: &[u32], b: &[u32]) -> f64 {
+    norme_2(a, b) * 5.0 / (dot(a, b) / n2(a) / n2(b))
+}
+
+#[allow(dead_code)]
+fn norme_max_<const N: usize>(a: &[u32], b: &[u32]) -> f64 {
+    let mut diff : Vec<u32> = (0..a.len()).map(|index|{a[index].abs_diff(b[index])}).collect();
+    diff.sort();
+    n2(&diff.into_iter().rev().take(N).collect::<Vec<u32>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day10>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day10>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day10>(2000);
+    }
+
+    // Fewest presses by breadth-first search, over the light patterns for part 1 and the counters for part 2
+    fn fewest_presses(machine: &Machine) -> (u32, u32) {
+        let mut lights = HashMap::from([(0u32, 0u32)]);
+        let mut queue = std::collections::VecDeque::from([0u32]);
+        while let Some(state) = queue.pop_front() {
+            for button in &machine.buttons_encoded {
+                if !lights.contains_key(&(state ^ button)) {
+                    lights.insert(state ^ button, lights[&state] + 1);
+                    queue.push_back(state ^ button);
+                }
+            }
+        }
+
+        let start = vec![0; machine.joltages.len()];
+        let mut joltages = HashMap::from([(start.clone(), 0u32)]);
+        let mut queue = std::collections::VecDeque::from([start]);
+        while let Some(state) = queue.pop_front() {
+            for button in &machine.buttons {
+                let next: Vec<u32> = state.iter().zip(button).map(|(nb, pressed)| nb + pressed).collect();
+                if next.iter().zip(&machine.joltages).all(|(nb, joltage)| nb <= joltage) && !joltages.contains_key(&next) {
+                    joltages.insert(next.clone(), joltages[&state] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        (lights[&machine.lights], joltages[&machine.joltages])
+    }
+
+    #[test]
+    fn test_presses_against_search() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(1..=3);
+            (0..size).map(|_| generate_machine(rng, 5, 4) + "\n").collect()
+        };
+        let solver = |machines: &Vec<Machine>| machines.iter().map(|machine| (machine.find_min_nb_press(), machine.i_have_cheated_p2())).collect::<Vec<_>>();
+        let reference = |machines: &Vec<Machine>| machines.iter().map(fewest_presses).collect::<Vec<_>>();
+        solution::check_differential::<Day10, Vec<(u32, u32)>>(1000, generate, solver, reference);
+    }
+
+    #[test]
+    fn test_exemple_p2() {
+        let string = Day10::INPUT.example(1).unwrap();
+        let parsed = MachineParsed::from_string(&string).unwrap();
+        let machines = Machine::from_mps(&parsed);
+        assert_eq!(machines.iter().fold(0, |res, machine|{res + machine.solve_from_0(norme_1, u32::MAX)}), 33);
+        assert_eq!(machines.iter().fold(0, |res, machine|{res + machine.solve_from_0(norme_2, u32::MAX)}), 33);
+        assert_eq!(machines.iter().fold(0, |res, machine|{res + machine.i_have_cheated_p2()}), 33);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day10::parse("[.##.] (3) (1,3) (2) {3,5,4,7}").is_ok());
+        assert!(Day10::parse(".##. (3) {3,5,4,7}").is_err());
+        assert!(Day10::parse("[.#x.] (3) {3,5,4,7}").is_err());
+        assert!(Day10::parse("[.##.] (4) {3,5,4,7}").is_err());
+        assert!(Day10::parse("[.##.] (3) {3,5,4}").is_err());
+    }
+}
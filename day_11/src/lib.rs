@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::log::{self, Level};
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+pub struct Device {
+   outs : Vec<String>,
+}
+
+struct WeigtedDevice {
+   outs : Vec<String>,
+   done : bool,
+   weight : u64,
+}
+
+impl Device {
+    pub fn outs(&self) -> &[String] {
+        &self.outs
+    }
+
+    // One device per line with the devices it leads to, like 'aaa: you hhh', by name
+    pub fn from_string(string : &str) -> Result<HashMap<String, Device>>{
+        let mut lines = vec![];
+        for line in string.lines() {
+            let (name, outs) = parse::split_once(line, ':', "a device like 'aaa: you hhh'")?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(ParseError::new(name, "expected a device name").into());
+            }
+            if lines.iter().any(|(other, _)| *other == name) {
+                return Err(ParseError::new(name, format!("device '{}' is defined twice", name)).into());
+            }
+            lines.push((name, outs.split_whitespace().collect::<Vec<&str>>()));
+        }
+
+        // Every output must lead to a known device, "out" being the only implicit one
+        for (_, outs) in &lines {
+            if let Some(unknown) = outs.iter().find(|out| **out != "out" && !lines.iter().any(|(name, _)| name == *out)) {
+                return Err(ParseError::new(unknown, format!("unknown device '{}'", unknown)).into());
+            }
+        }
+
+        Ok(HashMap::from_iter(lines.into_iter().map(|(name, outs)| {
+            (name.to_string(), Device{
+                outs : outs.iter().map(|str| {str.to_string()}).collect(),
+            })
+        })))
+    }
+
+    fn to_weighted(&self) -> WeigtedDevice{
+        WeigtedDevice { weight : 0u64, done : false, outs: self.outs.to_vec() }
+    }
+}
+
+fn log_devices(list : &HashMap<String, Device>){
+    for (from, to) in list.iter(){
+        aoc_common::debug!("{} : {}", from, to.outs.join(" "));
+    }
+}
+
+fn log_wdevices(end : &str, list : &HashMap<String, WeigtedDevice>){
+    for (from, to) in list.iter(){
+        aoc_common::trace!("{} -> {} = {}: {}", from, end, to.weight, to.outs.join(" "));
+    }
+}
+
+fn to_weighted(list : &HashMap<String, Device>) -> HashMap<String, WeigtedDevice>{
+    HashMap::from_iter(list.iter().map(|(k, v)| {(k.clone(), v.to_weighted())}))
+}
+
+fn reverse_device(list : &HashMap<String, Device>) -> HashMap<String, Device>{
+    let mut res :HashMap<String, Device> = HashMap::new();
+
+    for (from, to) in list{
+        for out in to.outs.iter(){
+            res.entry(out.clone()).or_insert(Device{ outs : vec![]}).outs.push(from.clone());
+        }
+    }
+
+    res
+}
+
+fn fill_device(list : &HashMap<String, Device>, rev : &HashMap<String, Device>, end : &str) -> HashMap<String, WeigtedDevice>{
+    let mut waiting = vec![String::from("out")];
+    let mut res : HashMap<String, WeigtedDevice> = to_weighted(list);
+    
+    res.insert(String::from("out"),WeigtedDevice{ weight: 0u64, done : false, outs : vec![String::new()]});
+
+    res.get_mut(end).unwrap().weight = 1u64;
+
+    while !waiting.is_empty() {
+        let current = &waiting.pop().unwrap();
+
+        if res[current].outs.iter().any(|x|{res.contains_key(x) && !res[x].done}){
+            res[current].outs.iter().for_each(|x|{if !res[x].done{waiting.push(x.clone());}});
+            continue;
+        }
+        if res[current].done { continue; }
+
+        res.get_mut(current).unwrap().done = true;
+
+        if rev.contains_key(current) {
+            let precs = rev[current].outs.clone();
+            for prec in precs{
+                res.get_mut(&prec).unwrap().weight += res[current].weight;
+                waiting.push(prec);
+            }
+        }
+    }
+    if log::enabled(Level::Trace) {
+        log_wdevices(end, &res);
+    }
+    res
+}
+
+// Number of paths from device `from` to device `to`, which may be "out"
+pub fn paths_between(devices : &HashMap<String, Device>, from : &str, to : &str) -> u64{
+    if to != "out" && !devices.contains_key(to) {
+        return 0;
+    }
+    fill_device(devices, &reverse_device(devices), to).get(from).map_or(0, |device| device.weight)
+}
+
+// Paths from "you" to "out"
+pub fn get_p1(devices : &HashMap<String, Device>) -> u64{
+    let reversed = reverse_device(devices);
+    let weighted = fill_device(devices, &reversed, "out");
+
+    weighted[&String::from("you")].weight
+}
+
+// Paths from "svr" to "out" through both "dac" and "fft"
+pub fn get_p2(devices : &HashMap<String, Device>) -> u64{
+    let reversed = reverse_device(devices);
+    let weighted_out = fill_device(devices, &reversed, "out");
+    let weighted_dac = fill_device(devices, &reversed, "dac");
+    let weighted_fft = fill_device(devices, &reversed, "fft");
+
+    let svr_dac = weighted_dac[&String::from("svr")].weight;
+    let dac_fft = weighted_fft[&String::from("dac")].weight;
+    let fft_out = weighted_out[&String::from("fft")].weight;
+
+    let svr_fft = weighted_fft[&String::from("svr")].weight;
+    let fft_dac = weighted_dac[&String::from("fft")].weight;
+    let dac_out = weighted_out[&String::from("dac")].weight;
+
+    svr_dac*dac_fft*fft_out + svr_fft*fft_dac*dac_out
+}
+
+// The number of paths multiplies at most by 3 per layer, 30 layers keep it within a u64
+const GENERATED_MAX_LAYERS: usize = 30;
+
+// About `size` devices in layers, each one leading to 1 to 3 devices of the next layer and the last layer to "out".
+// "svr" and "you" open the first layers, "dac" and "fft" sit in two different layers further down.
+fn generate_devices(rng : &mut Rng, size : usize) -> String {
+    let nb_layers = size.clamp(4, GENERATED_MAX_LAYERS);
+    let width = size.div_ceil(nb_layers);
+    let mut names: Vec<String> = vec![];
+    while names.len() < nb_layers * width {
+        let name: String = (0..3).map(|_| char::from(b'a' + rng.below(26) as u8)).collect();
+        if !["you", "out", "svr", "dac", "fft"].contains(&name.as_str()) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut layers: Vec<Vec<String>> = names.chunks(width).map(|layer| layer.to_vec()).collect();
+    let (dac, fft) = (rng.range(2..=nb_layers as u64 - 2) as usize, rng.range(2..=nb_layers as u64 - 1) as usize);
+    layers[0][0] = String::from("svr");
+    layers[1][0] = String::from("you");
+    layers[dac][0] = String::from("dac");
+    layers[if fft == dac { dac + 1 } else { fft }][width - 1] = String::from("fft");
+
+    let mut lines = vec![];
+    for (index, layer) in layers.iter().enumerate() {
+        for name in layer {
+            let outs = match layers.get(index + 1) {
+                Some(next) => {
+                    let mut outs: Vec<&str> = (0..rng.range(1..=3)).map(|_| rng.pick(next).as_str()).collect();
+                    outs.sort();
+                    outs.dedup();
+                    outs.join(" ")
+                }
+                None => String::from("out"),
+            };
+            lines.push(format!("{}: {}\n", name, outs));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = HashMap<String, Device>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Device>> {
+        let devices = Device::from_string(input)?;
+        if log::enabled(Level::Debug) {
+            log_devices(&devices);
+        }
+        Ok(devices)
+    }
+
+    fn part1(devices: &HashMap<String, Device>) -> Answer {
+        get_p1(devices).into()
+    }
+
+    fn part2(devices: &HashMap<String, Device>) -> Answer {
+        get_p2(devices).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_devices(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day11>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day11>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day11>(2000);
+    }
+
+    // Walks every path, only usable on small graphs
+    fn count_paths(devices: &HashMap<String, Device>, from: &str, seen: &[&str]) -> (u64, u64) {
+        if from == "out" {
+            let through_both = seen.contains(&"dac") && seen.contains(&"fft");
+            return (1, if through_both { 1 } else { 0 });
+        }
+        let mut seen = seen.to_vec();
+        seen.push(from);
+        devices[from].outs.iter().fold((0, 0), |(all, both), out| {
+            let (out_all, out_both) = count_paths(devices, out, &seen);
+            (all + out_all, both + out_both)
+        })
+    }
+
+    #[test]
+    fn test_paths_against_walk() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(4..=12) as usize;
+            Day11::generate(rng, size)
+        };
+        let solver = |devices: &HashMap<String, Device>| (get_p1(devices), get_p2(devices));
+        let reference = |devices: &HashMap<String, Device>| (count_paths(devices, "you", &[]).0, count_paths(devices, "svr", &[]).1);
+        solution::check_differential::<Day11, (u64, u64)>(1000, generate, solver, reference);
+    }
+
+    #[test]
+    fn test_paths_between() {
+        let devices = Day11::parse("you: aaa bbb\naaa: out\nbbb: aaa out").unwrap();
+        assert_eq!(paths_between(&devices, "you", "out"), 3);
+        assert_eq!(paths_between(&devices, "you", "aaa"), 2);
+        assert_eq!(paths_between(&devices, "aaa", "bbb"), 0);
+        assert_eq!(paths_between(&devices, "you", "zzz"), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day11::parse("you: aaa out\naaa: out").is_ok());
+        assert!(Day11::parse("you aaa").is_err());
+        assert!(Day11::parse("you: aaa\nyou: out").is_err());
+        assert!(Day11::parse("you: bbb").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+pub struct Shape {
+    shape : u32,
+}
+
+pub struct Grid {
+    x : u32,
+    y : u32,
+    shapes_nb : Vec<u32>
+}
+
+impl Grid {
+    // A region like '12x5: 1 0 1 0 2 2' with a count for each of the `nb_shapes` shapes
+    pub fn from_string(string : &str, nb_shapes : usize) -> Result<Grid> {
+        let (size, counts) = parse::split_once(string, ':', "a region like '12x5: 1 0 1 0 2 2'")?;
+        let (x, y) = parse::split_once(size, 'x', "a size like '12x5'")?;
+        let shapes_nb = counts.split_whitespace().map(parse::number).collect::<Result<Vec<u32>>>()?;
+        if shapes_nb.len() != nb_shapes {
+            return Err(ParseError::new(counts, format!("expected {} shape counts, found {}", nb_shapes, shapes_nb.len())).into());
+        }
+
+        Ok(Grid{x: parse::number(x)?, y: parse::number(y)?, shapes_nb})
+    }
+    
+    pub fn does_fit_naive(&self, shapes : &[Shape]) -> bool{
+        shapes.iter().enumerate().fold(0, |res, (index, shape)|{res + shape.get_area()*self.shapes_nb[index]}) <= self.get_area()
+    }
+
+    pub fn get_area(& self) -> u32{
+        self.x * self.y
+    }
+}
+
+impl Shape {
+    // Shape number `index`: its header like '0:' then 3 rows of 3 '#' or '.'
+    pub fn from_string(section : &str, index : usize) -> Result<Shape>{
+        let mut lines = section.lines();
+        let header = lines.next().unwrap_or(section);
+        if header.strip_suffix(':') != Some(index.to_string().as_str()) {
+            return Err(ParseError::new(header, format!("expected the header of shape {} like '{}:'", index, index)).into());
+        }
+        let strings = lines.collect::<Vec<&str>>();
+        if strings.len() != 3 {
+            return Err(ParseError::new(section, "expected a shape of 3 rows").into());
+        }
+        for row in &strings {
+            if let Some((i, c)) = row.char_indices().find(|(_, c)| *c != '#' && *c != '.') {
+                return Err(ParseError::new(&row[i..i + c.len_utf8()], format!("expected '#' or '.', found '{}'", c)).into());
+            }
+            if row.len() != 3 {
+                return Err(ParseError::new(row, format!("expected a row of 3 cells, found {}", row.len())).into());
+            }
+        }
+
+        let shape = Shape{shape : (String::new()+strings[0]+strings[1]+strings[2]).chars().fold(0, |res, c|{res*2 + if c == '#' {1} else {0}})};
+        if shape.shape == 0 {
+            return Err(ParseError::new(strings[0], "expected a shape with at least one '#'").into());
+        }
+        Ok(shape)
+    }
+
+    pub fn get_area(& self) -> u32{
+        self.shape.count_ones()
+    }
+}
+
+// Number of regions whose area is enough for all their shapes
+pub fn get_p1_naive(shapes : &[Shape], grids : &[Grid]) -> u32{
+    grids.iter().fold(0, |res, grid| {res + if grid.does_fit_naive(shapes) {1} else {0} })
+}
+
+pub fn vec_from_string(string: &str) -> Result<(Vec<Shape>, Vec<Grid>)>{
+    // Every section is a shape but the last one, which lists the regions
+    let mut shape_sections = parse::sections(string);
+    let Some(grids_section) = shape_sections.pop() else {
+        return Err(ParseError::new(string, "expected shapes followed by regions").into());
+    };
+    let shape_part = shape_sections.iter().enumerate().map(|(index, section)| Shape::from_string(section, index)).collect::<Result<Vec<Shape>>>()?;
+    let grids_part = grids_section.lines().map(|s|{Grid::from_string(s, shape_part.len())}).collect::<Result<Vec<Grid>>>()?;
+    Ok((shape_part, grids_part))
+}
+
+// Six random shapes of 5 to 7 cells, with their areas
+fn generate_shapes(rng : &mut Rng) -> (String, Vec<u64>) {
+    let mut res = String::new();
+    let mut areas = vec![];
+    for index in 0..6 {
+        let cells: Vec<u64> = loop {
+            let cells: Vec<u64> = (0..9).filter(|_| rng.chance(0.7)).collect();
+            if (5..=7).contains(&cells.len()) {break cells;}
+        };
+        areas.push(cells.len() as u64);
+        let rows: Vec<String> = (0..3).map(|row| (0..3).map(|col| if cells.contains(&(row * 3 + col)) {'#'} else {'.'}).collect()).collect();
+        res += &format!("{}:\n{}\n\n", index, rows.join("\n"));
+    }
+    (res, areas)
+}
+
+// Six random shapes and `size` regions. Like the puzzle, every region either fits all its shapes in
+// their own 3x3 box or is too small for their area, so the area check is the answer.
+fn generate_regions(rng : &mut Rng, size : usize) -> String {
+    let (mut res, areas) = generate_shapes(rng);
+    for _ in 0..size.max(1) {
+        let (x, y) = (rng.range(4..=50), rng.range(4..=50));
+        let mut counts = [0u64; 6];
+        if rng.chance(0.5) {
+            for _ in 0..rng.range(1..=(x / 3) * (y / 3)) {
+                counts[rng.below(6) as usize] += 1;
+            }
+        } else {
+            while counts.iter().zip(&areas).map(|(count, area)| count * area).sum::<u64>() <= x * y {
+                counts[rng.below(6) as usize] += 1;
+            }
+        }
+        let counts: Vec<String> = counts.iter().map(|count| count.to_string()).collect();
+        res += &format!("{}x{}: {}\n", x, y, counts.join(" "));
+    }
+    res
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = (Vec<Shape>, Vec<Grid>);
+
+    fn parse(input: &str) -> Result<(Vec<Shape>, Vec<Grid>)> {
+        vec_from_string(input)
+    }
+
+    fn part1((shapes, grids): &(Vec<Shape>, Vec<Grid>)) -> Answer {
+        get_p1_naive(shapes, grids).into()
+    }
+
+    // The last day only has one puzzle
+    fn part2(_: &(Vec<Shape>, Vec<Grid>)) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_regions(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day12>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day12>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day12>(2000);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let string = Day12::INPUT.example(1).unwrap();
+
+        assert!(Day12::parse(&string.replacen("##.", "#x.", 1)).is_err());
+        assert!(Day12::parse(&string.replacen("##.", "##", 1)).is_err());
+        assert!(Day12::parse(&string.replacen("4x4: 0 0 0 0 2 0", "4x4: 0 0 2", 1)).is_err());
+        assert!(Day12::parse(&string.replacen("4x4:", "4 by 4:", 1)).is_err());
+        assert!(Day12::parse(&string.replacen("1:", "7:", 1)).is_err());
+        assert!(Day12::parse(&string.replacen("##.\n\n", "##.\n", 1)).is_err());
+
+        let (shapes, grids) = vec_from_string(&string.replace('\n', "\r\n")).unwrap();
+        let (lf_shapes, lf_grids) = vec_from_string(&string).unwrap();
+        assert_eq!(get_p1_naive(&shapes, &grids), get_p1_naive(&lf_shapes, &lf_grids));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,224 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{cancel, parse, Answer, Result, Solution};
+
+#[derive(Clone)]
+struct MatchingData {
+    pattern : String,
+    index : usize,
+    count : u32,
+    count_max : u32,
+    state : ParsingState,
+}
+
+impl MatchingData {
+    fn new(max : u32) -> MatchingData {
+        MatchingData {
+            pattern : String::new(),
+            index : 0,
+            count : 0,
+            count_max : max,
+            state : ParsingState::Creating,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum ParsingState {
+    Creating,
+    Matching,
+}
+
+fn rec_check_num_str(mut remain : String, mut data : MatchingData) -> bool{
+    if !remain.is_empty() {
+        let digit = remain.remove(0);
+        let mut res = false;
+
+        if data.pattern.chars().nth(data.index) == Some(digit) {
+            let mut new_data = data.clone();
+            new_data.state = ParsingState::Matching;
+            new_data.index += 1;
+
+            if new_data.index == new_data.pattern.len() {
+                new_data.index = 0;
+                new_data.count += 1;
+            }
+
+            res |= rec_check_num_str(remain.clone(), new_data);
+        }
+
+        if matches!(data.state, ParsingState::Creating) {
+            data.pattern.push(digit);
+            res |= rec_check_num_str(remain, data);
+        }
+        res
+    }
+    else {
+        matches!(data.state, ParsingState::Matching) && data.index == 0 && data.count < data.count_max && data.count > 0
+    }
+}
+
+fn check_number(number: u64, data : MatchingData) -> bool{
+    rec_check_num_str(number.to_string(), data)
+}
+
+// An ID made of a sequence of digits repeated twice, like 6464
+pub fn check_number_p1(number: u64) -> bool{
+    rec_check_num_str(number.to_string(), MatchingData::new(2))
+}
+
+// An ID made of a sequence of digits repeated at least twice, like 121212
+pub fn check_number_p2(number: u64) -> bool{
+    rec_check_num_str(number.to_string(), MatchingData::new(u32::MAX))
+}
+
+// Number of invalid IDs in the inclusive range
+pub fn check_range_p1(range : (u64, u64)) -> u64{
+    sum_check_range(range, MatchingData::new(2), false)
+}
+
+pub fn check_range_p2(range : (u64, u64)) -> u64{
+    sum_check_range(range, MatchingData::new(u32::MAX), false)
+}
+
+// Sum of the invalid IDs in the inclusive range
+pub fn sum_range_p1(range : (u64, u64)) -> u64{
+    sum_check_range(range, MatchingData::new(2), true)
+}
+
+pub fn sum_range_p2(range : (u64, u64)) -> u64{
+    sum_check_range(range, MatchingData::new(u32::MAX), true)
+}
+
+fn sum_check_range(range : (u64, u64), data : MatchingData, sum : bool) -> u64{
+    (range.0..=range.1).take_while(|_| !cancel::cancelled()).fold(0, |res, number|{res + if check_number(number, data.clone()) {if sum {number} else {1}} else {0} })
+}
+
+// A range like '11-22'
+pub fn range_from_str(string: &str) -> Result<(u64, u64)>{
+    let pair = parse::split_once(string.trim(), '-', "a range like '11-22'")?;
+    aoc_common::debug!("range {} to {}", pair.0, pair.1);
+    Ok((parse::number(pair.0)?, parse::number(pair.1)?))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Vec<(u64, u64)>> {
+        input.trim_end().split(',').map(range_from_str).collect()
+    }
+
+    fn part1(ranges: &Vec<(u64, u64)>) -> Answer {
+        ranges.iter().fold(0, |res, range|{ res + sum_range_p1(*range) }).into()
+    }
+
+    fn part2(ranges: &Vec<(u64, u64)>) -> Answer {
+        ranges.iter().fold(0, |res, range|{ res + sum_range_p2(*range) }).into()
+    }
+
+    // `size` disjoint ranges of up to 100000 IDs with 1 to 10 digits, in random order
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut starts: Vec<u64> = (0..size.max(1)).map(|_| rng.range(1..=9_999_999_999)).collect();
+        starts.sort();
+        starts.dedup();
+        let mut ranges: Vec<String> = starts.iter().enumerate().map(|(index, start)| {
+            let next = starts.get(index + 1).map_or(u64::MAX, |next| next - 1);
+            format!("{}-{}", start, (start + rng.below(100_000)).min(next))
+        }).collect();
+        rng.shuffle(&mut ranges);
+        ranges.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day2>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day2>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day2>(2000);
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(check_range_p1((11, 22)), 2);
+        assert_eq!(check_range_p1((1188511880,1188511890)), 1);
+        assert_eq!(check_range_p1((222220, 222224)), 1);
+        assert_eq!(check_range_p1((1698522, 1698528)), 0);
+        assert_eq!(check_range_p1((446443, 446449)), 1);
+        assert_eq!(check_range_p1((38593856, 38593862)), 1);
+        assert_eq!(check_range_p1((2121212118, 2121212124)), 0);
+        assert_eq!(check_range_p1((565653, 565659)), 0);
+    }
+
+    #[test]
+    fn test_range_p2() {
+        assert_eq!(check_range_p2((11, 22)), 2);
+        assert_eq!(check_range_p2((95, 115)), 2);
+        assert_eq!(check_range_p2((1188511880,1188511890)), 1);
+        assert_eq!(check_range_p2((222220, 222224)), 1);
+        assert_eq!(check_range_p2((1698522, 1698528)), 0);
+        assert_eq!(check_range_p2((446443, 446449)), 1);
+        assert_eq!(check_range_p2((38593856, 38593862)), 1);
+        assert_eq!(check_range_p2((2121212118, 2121212124)), 1);
+        assert_eq!(check_range_p2((565653, 565659)), 1);
+    }
+
+    #[test]
+    fn test_str() {
+        assert_eq!(check_range_p1(range_from_str("11-22").unwrap()), 2);
+        assert_eq!(check_range_p1(range_from_str("1188511880-1188511890").unwrap()), 1);
+        assert_eq!(check_range_p1(range_from_str("222220-222224").unwrap()), 1);
+        assert_eq!(check_range_p1(range_from_str("1698522-1698528").unwrap()), 0);
+        assert_eq!(check_range_p1(range_from_str("446443-446449").unwrap()), 1);
+        assert_eq!(check_range_p1(range_from_str("38593856-38593862").unwrap()), 1);
+        assert_eq!(check_range_p1(range_from_str("2121212118-2121212124").unwrap()), 0);
+        assert_eq!(check_range_p1(range_from_str("565653-565659").unwrap()), 0);
+
+        assert!(range_from_str("565653").is_err());
+        assert!(range_from_str("565653-").is_err());
+    }
+
+     #[test]
+    fn test_number_p1() {
+        assert!(check_number_p1(11));
+        assert!(check_number_p1(110110));
+        assert!(!check_number_p1(2121212121));
+        assert!(!check_number_p1(123123123));
+
+        assert!(check_number_p1(1212));
+        assert!(!check_number_p1(11111));
+    }
+
+     #[test]
+    fn test_number_p2() {
+        assert!(check_number_p2(11));
+        assert!(check_number_p2(110110));
+        assert!(check_number_p2(2121212121));
+        assert!(check_number_p2(123123123));
+
+        assert!(check_number_p2(1212));
+        assert!(check_number_p2(11111));
+    }
+
+    #[test]
+    fn test_parsing(){
+        assert_eq!("9393974421".parse(), Ok(9393974421u64));
+        assert_eq!("9393862801".parse(), Ok(9393862801u64));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+// Part 2 turns on 12 batteries in every bank
+const NB_BATTERIES_P2: usize = 12;
+
+// One digit per battery, a bank has at least NB_BATTERIES_P2 of them
+pub fn parse_bank(s: &str) -> Result<Vec<u64>> {
+    let bank = s.char_indices().map(|(index, c)| {
+        c.to_digit(10).map(|digit| digit as u64).ok_or_else(|| ParseError::new(&s[index..index + c.len_utf8()], format!("expected a battery joltage digit, found '{}'", c)).into())
+    }).collect::<Result<Vec<u64>>>()?;
+
+    if bank.len() < NB_BATTERIES_P2 {
+        return Err(ParseError::new(parse::end_of(s), format!("expected at least {} batteries, found {}", NB_BATTERIES_P2, bank.len())).into());
+    }
+    Ok(bank)
+}
+
+#[allow(dead_code)]
+fn find_max_in_string_p1(s: &str) -> u64 {
+    find_max_in_string_p2(s, 2)
+}
+
+#[allow(dead_code)]
+fn find_max_in_string_p2(s: &str, nb : usize) -> u64 {
+    find_max_in_bank(&parse_bank(s).unwrap(), nb)
+}
+
+// Largest joltage made of `nb` batteries kept in order, `nb` must not exceed the bank's size
+pub fn find_max_in_bank(numbers: &[u64], mut nb : usize) -> u64 {
+    let n = numbers.len(); 
+    assert!(nb <= n, "cannot turn on {} of {} batteries", nb, n);
+
+    let mut last_index = 0;
+    let mut res = 0u64;
+
+    while nb > 0 {
+        let mut slice_s = numbers[last_index..n-nb+1].iter();
+        let digit = *slice_s.clone().max().expect("Empty list ?");
+        last_index += slice_s.position(|x| *x == digit).unwrap() + 1;
+
+        nb -= 1;
+        res = res * 10 + digit;
+    }
+    res
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u64>>> {
+        input.lines().map(parse_bank).collect()
+    }
+
+    fn part1(banks: &Vec<Vec<u64>>) -> Answer {
+        banks.iter().fold(0u64, |acc, bank| acc + find_max_in_bank(bank, 2)).into()
+    }
+
+    fn part2(banks: &Vec<Vec<u64>>) -> Answer {
+        banks.iter().fold(0u64, |acc, bank| acc + find_max_in_bank(bank, NB_BATTERIES_P2)).into()
+    }
+
+    // `size` banks of 100 batteries
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| (0..100).map(|_| char::from(b'1' + rng.below(9) as u8)).collect::<String>() + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day3>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day3>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day3>(2000);
+    }
+
+    // Largest number of `nb` digits kept in order: best[i][k] is the best with k digits taken from bank[i..]
+    fn max_in_bank_dp(bank: &[u64], nb: usize) -> u64 {
+        let mut best = vec![vec![None; nb + 1]; bank.len() + 1];
+        for row in best.iter_mut() {
+            row[0] = Some(0u64);
+        }
+        for i in (0..bank.len()).rev() {
+            for k in 1..=nb {
+                let take = best[i + 1][k - 1].map(|rest| bank[i] * 10u64.pow(k as u32 - 1) + rest);
+                best[i][k] = take.max(best[i + 1][k]);
+            }
+        }
+        best[0][nb].unwrap()
+    }
+
+    #[test]
+    fn test_max_in_bank_against_reference() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(1..=5) as usize;
+            Day3::generate(rng, size)
+        };
+        let pairs = |banks: &Vec<Vec<u64>>| -> Vec<u64> {
+            banks.iter().map(|bank| (0..bank.len()).flat_map(|i| (i + 1..bank.len()).map(move |j| bank[i] * 10 + bank[j])).max().unwrap()).collect()
+        };
+        solution::check_differential::<Day3, Vec<u64>>(1000, generate, |banks| banks.iter().map(|bank| find_max_in_bank(bank, 2)).collect(), pairs);
+
+        let solver = |banks: &Vec<Vec<u64>>| banks.iter().map(|bank| find_max_in_bank(bank, NB_BATTERIES_P2)).collect::<Vec<u64>>();
+        let reference = |banks: &Vec<Vec<u64>>| banks.iter().map(|bank| max_in_bank_dp(bank, NB_BATTERIES_P2)).collect::<Vec<u64>>();
+        solution::check_differential::<Day3, Vec<u64>>(1000, generate, solver, reference);
+    }
+
+    #[test]
+    fn test_exemple() {
+        assert_eq!(find_max_in_string_p1("987654321111111"), 98);
+        assert_eq!(find_max_in_string_p1("811111111111119"), 89);
+        assert_eq!(find_max_in_string_p1("234234234234278"), 78);
+        assert_eq!(find_max_in_string_p1("818181911112111"), 92);
+    }
+
+    #[test]
+    fn test_exemple_p2() {
+        assert_eq!(find_max_in_string_p2("987654321111111", 12), 987654321111u64);
+        assert_eq!(find_max_in_string_p2("811111111111119", 12), 811111111119u64);
+        assert_eq!(find_max_in_string_p2("234234234234278", 12), 434234234278u64);
+        assert_eq!(find_max_in_string_p2("818181911112111", 12), 888911112111u64);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse_bank("98765432111x111").is_err());
+        assert!(parse_bank("9876543").is_err());
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,208 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+static HAVE_ROLL: char = '@';
+static NO_ROLL: char = '.';
+
+// The grid functions expect a non-empty rectangular grid of '@' and '.', as parsed by Day4
+// Rolls with fewer than 4 rolls around them
+pub fn count_accessible(lines: Vec<String>) -> u32{
+    let nx = lines[0].len() as i32;
+    let ny = lines.len() as i32;
+
+    let mut res = 0;
+
+    for x in 0..nx {
+        for y in 0..ny {
+            if lines[y as usize].chars().nth(x as usize).unwrap() != HAVE_ROLL {
+                continue;
+            }
+
+            let mut neighbors = 0;
+            for dx in -1..=1{
+                if x + dx >= 0 && x + dx < nx {
+                    for dy in -1..=1{
+                        if y + dy >= 0 && y + dy < ny {
+                            let case = lines[(y+dy) as usize].chars().nth((x+dx) as usize).unwrap(); 
+                            if case == HAVE_ROLL && !(dx == 0 && dy == 0) {
+                                neighbors += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            if neighbors < 4 {
+                res += 1;
+            }
+
+        }
+    }
+
+    res
+}
+
+// The grid once every accessible roll is removed
+pub fn remove_accessible(lines: Vec<String>) -> Vec<String>{
+    let nx = lines[0].len() as i32;
+    let ny = lines.len() as i32;
+
+    let mut res = Vec::new();
+    
+    for y in 0..ny {
+        res.push(String::new());
+        for x in 0..nx {
+            if lines[y as usize].chars().nth(x as usize).unwrap() != HAVE_ROLL {
+                res[y as usize].push(NO_ROLL);
+                continue;
+            }
+
+            let mut neighbors = 0;
+            for dx in -1..=1{
+                if x + dx >= 0 && x + dx < nx {
+                    for dy in -1..=1{
+                        if y + dy >= 0 && y + dy < ny {
+                            let case = lines[(y+dy) as usize].chars().nth((x+dx) as usize).unwrap(); 
+                            if case == HAVE_ROLL && !(dx == 0 && dy == 0) {
+                                neighbors += 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            res[y as usize].push(if neighbors < 4 {NO_ROLL} else {HAVE_ROLL});
+
+        }
+    }
+    res
+
+}
+
+// Rolls removed by repeating remove_accessible until nothing changes
+pub fn count_isolated(lines: Vec<String>) -> u32{
+    let mut last_nb = count_roll(&lines);
+    let mut current_lines = lines;
+
+    last_nb - loop {
+        current_lines = remove_accessible(current_lines);
+        let current_nb = count_roll(&current_lines);
+        if current_nb == last_nb { break current_nb}
+        last_nb = current_nb;
+    }
+}
+
+pub fn count_roll(lines: &[String]) -> u32{
+    let nx = lines[0].len() as i32;
+    let ny = lines.len() as i32;
+
+    let mut res = 0;
+
+    for x in 0..nx {
+        for y in 0..ny {
+            if lines[y as usize].chars().nth(x as usize).unwrap() == HAVE_ROLL {
+                res += 1;
+            }
+        }
+    }
+    res
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        let width = input.lines().next().map_or(0, |line| line.len());
+        if width == 0 {
+            return Err(ParseError::new(input, "expected a grid of rolls").into());
+        }
+
+        for line in input.lines() {
+            if let Some((index, c)) = line.char_indices().find(|(_, c)| *c != HAVE_ROLL && *c != NO_ROLL) {
+                return Err(ParseError::new(&line[index..index + c.len_utf8()], format!("expected '{}' or '{}', found '{}'", HAVE_ROLL, NO_ROLL, c)).into());
+            }
+            if line.len() != width {
+                return Err(ParseError::new(parse::end_of(line), format!("expected {} cells like the first row, found {}", width, line.len())).into());
+            }
+        }
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        count_accessible(lines.clone()).into()
+    }
+
+    fn part2(lines: &Vec<String>) -> Answer {
+        count_isolated(lines.clone()).into()
+    }
+
+    // A `size` x `size` grid
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size).map(|_| (0..size).map(|_| if rng.chance(0.6) { HAVE_ROLL } else { NO_ROLL }).collect::<String>() + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day4>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day4>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day4>(2000);
+    }
+
+    // Removes accessible rolls one at a time instead of a whole round at once
+    fn removed_one_by_one(lines: &[String]) -> (u32, u32) {
+        let mut grid: Vec<Vec<bool>> = lines.iter().map(|line| line.chars().map(|c| c == HAVE_ROLL).collect()).collect();
+        let accessible = |grid: &Vec<Vec<bool>>, x: usize, y: usize| {
+            let neighbors = (y.saturating_sub(1)..=(y + 1).min(grid.len() - 1))
+                .flat_map(|ny| (x.saturating_sub(1)..=(x + 1).min(grid[0].len() - 1)).map(move |nx| (nx, ny)))
+                .filter(|&(nx, ny)| (nx, ny) != (x, y) && grid[ny][nx])
+                .count();
+            grid[y][x] && neighbors < 4
+        };
+        let cells: Vec<(usize, usize)> = (0..grid.len()).flat_map(|y| (0..grid[0].len()).map(move |x| (x, y))).collect();
+
+        let first = cells.iter().filter(|&&(x, y)| accessible(&grid, x, y)).count() as u32;
+        let mut removed = 0;
+        while let Some(&(x, y)) = cells.iter().find(|&&(x, y)| accessible(&grid, x, y)) {
+            grid[y][x] = false;
+            removed += 1;
+        }
+        (first, removed)
+    }
+
+    #[test]
+    fn test_rescan_against_one_by_one() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(1..=10) as usize;
+            Day4::generate(rng, size)
+        };
+        let solver = |lines: &Vec<String>| (count_accessible(lines.clone()), count_isolated(lines.clone()));
+        solution::check_differential::<Day4, (u32, u32)>(1000, generate, solver, |lines| removed_one_by_one(lines));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day4::parse("..@@.\n@@@.@").is_ok());
+        assert!(Day4::parse("").is_err());
+        assert!(Day4::parse("..@@.\n@@x.@").is_err());
+        assert!(Day4::parse("..@@.\n@@.@").is_err());
+    }
+}
\ No newline at end of file
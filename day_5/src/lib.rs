@@ -0,0 +1,191 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    start: u64,
+    end: u64,
+}
+
+impl Range {
+    fn new(start: u64, end: u64) -> Range {
+        Range { start, end }
+    }
+
+    fn merge(&self, other: &Range) -> Option<Range> {
+        if self.end < other.start || other.end < self.start {
+            None
+        } else {
+            Some(Range::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        }
+    }
+
+    fn cmp(&self, other: &Range) -> std::cmp::Ordering {
+        self.start.cmp(&other.start)
+    }
+
+}
+
+// Whether the ID is in one of the inclusive ranges
+pub fn is_fresh(number : u64, list : &[(u64, u64)]) -> bool {
+    for range in list {
+        if number >= range.0 && number <= range.1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn number_fresh(fruits: &[u64], list : &[(u64, u64)]) -> u64 {
+    let mut count = 0;
+    for fruit in fruits {
+        if is_fresh(*fruit, list) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn merge_sorted(list : Vec<Range>) -> Vec<Range> {
+    let mut merged_dates: Vec<Range> = Vec::new();
+    for date in list {
+        match merged_dates.last().and_then(|last| last.merge(&date)) {
+            None => merged_dates.push(date),
+            Some(merged_date) => {
+                merged_dates.pop();
+                merged_dates.push(merged_date);
+            }
+        }
+    }
+
+    merged_dates
+}
+
+// Ranges like '3-5', one per line
+pub fn parse_dates(section : &str) -> Result<Vec<(u64, u64)>>{
+    section.lines().map(
+        |x| {
+            let (start, end) = parse::split_once(x, '-', "a range like '3-5'")?;
+            Ok((parse::number(start)?, parse::number(end)?))
+        }
+    ).collect()
+}
+
+// The inclusive ranges merged into sorted, non-overlapping ones
+pub fn merge_ranges(dates : &[(u64, u64)]) -> Vec<(u64, u64)>{
+    let mut dates: Vec<Range> = dates.iter().map(|date| Range::new(date.0, date.1)).collect();
+
+    dates.sort_by(|a, b| a.cmp(b));
+
+    merge_sorted(dates).iter().map(|date| (date.start, date.end)).collect()
+}
+
+// Number of IDs in at least one of the ranges
+pub fn number_range(dates : &[(u64, u64)]) -> u64{
+    merge_ranges(dates).iter().fold(0, |acc, date| acc + (date.1 - date.0 + 1))
+}
+
+pub struct Inventory {
+    pub dates: Vec<(u64, u64)>,
+    pub fruits: Vec<u64>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        let sections = parse::sections(input);
+        if let Some(extra) = sections.get(2) {
+            return Err(ParseError::new(extra, "expected a section of ranges then a section of ingredients").into());
+        }
+        let dates = parse_dates(sections.first().copied().unwrap_or(input))?;
+        let fruits = sections.get(1).map_or(Ok(vec![]), |section| section.lines().map(parse::number).collect())?;
+        Ok(Inventory { dates, fruits })
+    }
+
+    fn part1(inventory: &Inventory) -> Answer {
+        number_fresh(&inventory.fruits, &inventory.dates).into()
+    }
+
+    fn part2(inventory: &Inventory) -> Answer {
+        number_range(&inventory.dates).into()
+    }
+
+    // `size` ingredients, a fifth as many possibly overlapping fresh ranges
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let ranges: String = (0..(size / 5).max(1)).map(|_| {
+            let start = rng.range(1..=999_999_999_999_999);
+            format!("{}-{}\n", start, start + rng.below(10_000_000_000_000))
+        }).collect();
+        let ingredients: String = (0..size).map(|_| format!("{}\n", rng.range(1..=999_999_999_999_999))).collect();
+        ranges + "\n" + &ingredients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day5>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day5>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day5>(2000);
+    }
+
+    #[test]
+    fn test_exemple() {
+        let list = vec![(3, 5), (10, 14), (16, 20), (12, 18)];
+        assert!(!is_fresh(1, &list));
+        assert!(is_fresh(5, &list));
+        assert!(!is_fresh(8, &list));
+        assert!(is_fresh(11, &list));
+        assert!(is_fresh(17, &list));
+        assert!(!is_fresh(32, &list));
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(merge_ranges(&[(3, 5), (10, 14), (16, 20), (12, 18)]), vec![(3, 5), (10, 20)]);
+        assert_eq!(merge_ranges(&[(4, 4), (1, 9)]), vec![(1, 9)]);
+        assert_eq!(merge_ranges(&[]), vec![]);
+        assert_eq!(number_range(&[]), 0);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let example = Day5::INPUT.example(1).unwrap();
+        for string in [example.clone(), example.replace('\n', "\r\n")] {
+            let inventory = Day5::parse(&string).unwrap();
+            assert_eq!(Day5::part1(&inventory), 3);
+            assert_eq!(Day5::part2(&inventory), 14);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day5::parse("3-5\n10\n\n1").is_err());
+        assert!(Day5::parse("3-5\r\n\r\n1\r\nx").is_err());
+        assert!(Day5::parse("3-5\n\n1\n\n2").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub params : Vec<u64>,
+    pub operation: Operation,
+}
+
+impl Problem {
+    pub fn solve(&self) -> u64 {
+        match self.operation {
+            Operation::Add => self.params.iter().sum(),
+            Operation::Multiply => self.params.iter().product(),
+        }
+    }
+}
+
+fn operation(text: &str) -> Result<Operation> {
+    match text {
+        "+" => Ok(Operation::Add),
+        "*" => Ok(Operation::Multiply),
+        _ => Err(ParseError::new(text, format!("expected '+' or '*', found '{}'", text)).into()),
+    }
+}
+
+fn split_operations(data: &str) -> Result<(&str, Vec<&str>)> {
+    let mut lines = data.lines().collect::<Vec<&str>>();
+    match lines.pop() {
+        Some(o_line) if !lines.is_empty() => Ok((o_line, lines)),
+        _ => Err(ParseError::new(data, "expected rows of numbers followed by a row of operations").into()),
+    }
+}
+
+// Problems written in columns, each number on its own row
+pub fn process_data_p1(data: &str) -> Result<Vec<Problem>> {
+    let (o_line, lines) = split_operations(data)?;
+    let os = o_line.split_whitespace().collect::<Vec<&str>>();
+    let n = os.len();
+
+    let mut params_list = vec![];
+    for line in lines {
+        let params = line.split_whitespace().collect::<Vec<&str>>();
+        if params.len() != n {
+            return Err(ParseError::new(parse::end_of(line), format!("expected {} numbers, found {}", n, params.len())).into());
+        }
+        params_list.push(params);
+    }
+
+    let mut problems: Vec<Problem> = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut params: Vec<u64> = vec![];
+        for params_line in &params_list {
+            params.push(parse::number(params_line[i])?);
+        }
+        problems.push(Problem {params, operation: operation(os[i])? });
+    }
+    Ok(problems)
+}
+
+// Problems whose numbers are written vertically, one per column, the operation row gives where each problem starts
+pub fn process_data_p2(data: &str) -> Result<Vec<Problem>> {
+    let (o_line, lines) = split_operations(data)?;
+    let width = lines.iter().chain([&o_line]).map(|line| line.len()).max().unwrap_or(0);
+    let starts = o_line.char_indices().filter(|(_, c)| !c.is_whitespace()).collect::<Vec<(usize, char)>>();
+
+    let mut problems: Vec<Problem> = Vec::with_capacity(starts.len());
+    for (n, &(index, c)) in starts.iter().enumerate() {
+        let o_text = &o_line[index..index + c.len_utf8()];
+        let operation = operation(o_text)?;
+        let end = starts.get(n + 1).map_or(width, |(next, _)| next - 1);
+
+        let mut params: Vec<u64> = vec![];
+        for column in index..end {
+            // Lines may be shorter than the operation row, missing cells count as spaces
+            let param_str = lines.iter().map(|line| line.as_bytes().get(column).map_or(' ', |b| *b as char)).collect::<String>();
+            match param_str.trim().parse::<u64>() {
+                Ok(param) => params.push(param),
+                Err(_) => return Err(ParseError::new(o_text, format!("expected a number in column {}", column + 1)).into()),
+            }
+        }
+        problems.push(Problem {params, operation });
+    }
+    Ok(problems)
+}
+
+pub fn solve_problems(problems: &[Problem]) -> u64 {
+    problems.iter().fold(0, |res, p| res + p.solve())
+}
+
+// Part 1 reads the numbers by rows, part 2 by columns, so both layouts are parsed upfront
+pub struct Worksheet {
+    pub by_rows: Vec<Problem>,
+    pub by_columns: Vec<Problem>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Worksheet> {
+        Ok(Worksheet { by_rows: process_data_p1(input)?, by_columns: process_data_p2(input)? })
+    }
+
+    fn part1(worksheet: &Worksheet) -> Answer {
+        solve_problems(&worksheet.by_rows).into()
+    }
+
+    fn part2(worksheet: &Worksheet) -> Answer {
+        solve_problems(&worksheet.by_columns).into()
+    }
+
+    // `size` problems of 4 numbers with up to 4 digits, aligned left or right in their column
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut rows = vec![String::new(); 5];
+        for index in 0..size.max(1) {
+            let mut numbers: Vec<String> = (0..4).map(|_| {
+                let digits = rng.range(1..=4) as u32;
+                rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1).to_string()
+            }).collect();
+            // Longest first, so the digits of every column are contiguous when read top to bottom
+            numbers.sort_by_key(|number| std::cmp::Reverse(number.len()));
+            let width = numbers.iter().map(|number| number.len()).max().unwrap_or(1);
+            let left = rng.chance(0.5);
+            let separator = if index == 0 { "" } else { " " };
+            for (row, number) in rows.iter_mut().zip(&numbers) {
+                *row += &if left { format!("{}{:<width$}", separator, number) } else { format!("{}{:>width$}", separator, number) };
+            }
+            rows[4] += &format!("{}{:<width$}", separator, if rng.chance(0.5) { "+" } else { "*" });
+        }
+        rows.iter().map(|row| row.trim_end().to_string() + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day6>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day6>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day6>(2000);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day6::parse("1 2\n3 4\n* +").is_ok());
+        assert!(Day6::parse("1 2").is_err());
+        assert!(Day6::parse("1 2\n3\n* +").is_err());
+        assert!(Day6::parse("1 2\n3 4\n* -").is_err());
+        assert!(Day6::parse("1 x\n3 4\n* +").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+pub struct Manifold {
+    x_start: usize,
+    x : usize,
+    y : usize,
+    splitters : Vec<Vec<usize>>,
+}
+  
+impl Manifold {
+    // A grid of '.' and '^' splitters with the start 'S' on the first row
+    pub fn from_string(string : &str) -> Result<Manifold> {
+        let lines : Vec<&str> = string.lines().collect();
+        let first = lines.first().copied().unwrap_or(string);
+        let start = first.find('S').ok_or_else(|| ParseError::new(first, "expected a start 'S' on the first line"))?;
+        let width = first.len();
+
+        for line in &lines {
+            if let Some((i, c)) = line.char_indices().find(|(i, c)| !matches!(c, '.' | '^' | 'S') || (*c == '^' && (*i == 0 || *i + 1 == width))) {
+                let message = if c == '^' { "a splitter cannot be on the edge".to_string() } else { format!("expected '.' or '^', found '{}'", c) };
+                return Err(ParseError::new(&line[i..i + c.len_utf8()], message).into());
+            }
+            if line.len() != width {
+                return Err(ParseError::new(parse::end_of(line), format!("expected {} cells like the first row, found {}", width, line.len())).into());
+            }
+        }
+
+        let splitters : Vec<Vec<usize>> = lines.iter().map(
+            |line| {
+                line.chars().enumerate().fold(vec![], |mut v : Vec<usize>, ic| {if ic.1 == '^' {v.push(ic.0)} v})
+            }
+        ).collect();
+
+        Ok(Manifold {
+            x_start : start,
+            x: width,
+            y: lines.len(),
+            splitters,
+        })
+    }
+
+    // Number of splitters a ray reaches
+    pub fn count_split(& self) -> u32{
+        let mut res : u32 = 0;
+        let mut rays  = HashSet::from([self.x_start]);
+
+        for y in 0..self.y {
+            rays = rays.iter().fold(HashSet::new(), 
+                |mut v : HashSet<usize>, ray_x| {
+                    if self.splitters[y].contains(ray_x) {
+                        v.insert(*ray_x-1);
+                        v.insert(*ray_x+1);
+                        res += 1;
+                    } else {
+                        v.insert(*ray_x);
+                    } 
+                    v
+                }
+            )
+        }
+
+        res
+    }
+
+    #[allow(dead_code)]
+    // Naive first attempt, right but too inefficient to get the result, the reference of the differential test
+    fn count_timelines_naive(& self) -> u64 {
+        let mut res: u64 = 0;
+
+        let mut x_ray = self.x_start;
+        let mut y_ray = 0;
+
+        let mut splitters: Vec<(usize, usize)> = vec![];
+    
+        loop {
+            if self.splitters[y_ray].contains(&x_ray){
+                splitters.push((x_ray, y_ray));
+                x_ray -= 1;
+            }
+
+            y_ray += 1;
+
+            if y_ray == self.y {
+                res += 1;
+
+                if splitters.is_empty() {break res}
+
+                let last_checkpoint = splitters.pop().unwrap();
+                x_ray = last_checkpoint.0 + 1;
+                y_ray = last_checkpoint.1;
+            }
+        }
+    }
+    
+    // Number of paths a single particle can take down the manifold
+    pub fn count_timelines(& self) -> u64 {
+        let mut rays  =  vec![0; self.x];
+        rays[self.x_start] = 1;
+
+        for y in 1..self.y {
+            let mut new_rays:Vec<u64> = vec![0; self.x];
+            
+            rays.iter().enumerate().for_each(|(i, weight)|{
+                if self.splitters[y].contains(&i){
+                    new_rays[i-1] += weight;
+                    new_rays[i+1] += weight;
+                }else{
+                    new_rays[i] += weight;
+                }
+            });
+
+            rays = new_rays;
+        }
+
+        rays.iter().sum()
+    }
+}
+
+// The timelines double at most once per row of splitters, 60 of them keep the count within a u64
+const GENERATED_MAX_ROWS: usize = 121;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Manifold;
+
+    fn parse(input: &str) -> Result<Manifold> {
+        Manifold::from_string(input)
+    }
+
+    fn part1(manifold: &Manifold) -> Answer {
+        manifold.count_split().into()
+    }
+
+    fn part2(manifold: &Manifold) -> Answer {
+        manifold.count_timelines().into()
+    }
+
+    // A grid `size` cells wide, with splitters on every other row in the cone below the start
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = size.max(3);
+        let start = width / 2;
+        (0..width.min(GENERATED_MAX_ROWS)).map(|y| {
+            (0..width).map(|x| {
+                let in_cone = y > 0 && y.is_multiple_of(2) && x.abs_diff(start) <= y / 2 && (x + y / 2 - start).is_multiple_of(2);
+                if y == 0 && x == start {
+                    'S'
+                } else if in_cone && x > 0 && x + 1 < width && rng.chance(0.7) {
+                    '^'
+                } else {
+                    '.'
+                }
+            }).collect::<String>() + "\n"
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day7>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day7>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day7>(2000);
+    }
+
+    #[test]
+    fn test_timelines_against_naive() {
+        let generate = |rng: &mut Rng| {
+            let size = rng.range(3..=13) as usize;
+            Day7::generate(rng, size)
+        };
+        solution::check_differential::<Day7, u64>(2000, generate, Manifold::count_timelines, Manifold::count_timelines_naive);
+    }
+
+    #[test]
+    fn test_exemple_p2_naive() {
+        let string = Day7::INPUT.example(1).unwrap();
+        let manifold = Manifold::from_string(&string).unwrap();
+        assert_eq!(manifold.count_timelines_naive(), 40);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day7::parse("..S..\n..^..").is_ok());
+        assert!(Day7::parse(".....\n..^..").is_err());
+        assert!(Day7::parse("..S..\n^....").is_err());
+        assert!(Day7::parse("..S..\n..x..").is_err());
+        assert!(Day7::parse("..S..\n..^.").is_err());
+    }
+}
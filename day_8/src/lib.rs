@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::log::{self, Level};
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+
+#[derive(Eq, Hash, PartialEq)]
+pub struct JBox{
+    coords : Vec<u32>
+}
+
+impl JBox{
+    pub fn new(x : u32, y : u32, z : u32) -> JBox {
+        JBox { coords: vec![x, y, z] }
+    }
+
+    pub fn coords(&self) -> &[u32] {
+        &self.coords
+    }
+
+    // One box per line, like '162,817,812'
+    pub fn from_string(string : &str) -> Result<Vec<JBox>>{
+        string.lines().map(|line| {
+            let coords = line.split(",").map(parse::number).collect::<Result<Vec<u32>>>()?;
+            if coords.len() != 3 {
+                return Err(ParseError::new(line, format!("expected 3 coordinates like '162,817,812', found {}", coords.len())).into());
+            }
+            Ok(JBox { coords })
+        }).collect()
+    }
+
+    pub fn distance(&self, other : &JBox) -> f64 {
+        (0..3).fold(0.0, |res, i| {res + (self.coords[i] as f64 - other.coords[i] as f64) * (self.coords[i] as f64 - other.coords[i] as f64) }).sqrt()
+    }
+}
+
+impl fmt::Display for JBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        self.coords[0..self.coords.len()-1].iter().for_each(|x|{
+            write!(f, "{},", x).unwrap();
+        });
+        write!(f, "{})", self.coords.last().unwrap())?;
+        Ok(())
+    }
+}
+
+// Every pair of boxes, closest first
+pub fn get_sorted_pair(list : &[JBox]) -> Vec<(& JBox, & JBox)>{
+    let mut pairs = vec![];
+    for i in 0..list.len(){
+        for j in (i+1)..list.len(){
+            pairs.push((&list[i], &list[j]));
+        }
+    }
+
+    pairs.sort_by(|a, b|{a.0.distance(a.1).total_cmp(&b.0.distance(b.1))});
+
+    pairs
+}
+
+// Connects the `n_pair` closest pairs and multiplies the sizes of the 3 largest circuits,
+// there must be at least `n_pair` pairs and 3 circuits
+pub fn get_p1(pairs : &[(& JBox, & JBox)], n_pair : usize) -> u64{
+    let mut circuits : Vec<HashSet<& JBox>> = Vec::new();
+    for pair in &pairs[0..n_pair]{
+
+        match (circuits.iter().position(|set| {set.contains(&pair.0)}), circuits.iter().position(|set| {set.contains(&pair.1)})){
+            (None, None) => {circuits.push(HashSet::from([pair.0, pair.1]));},
+            (Some(i0), Some(i1)) => { 
+                if i0 != i1 {
+                    circuits[i0] = circuits[i0].union(&circuits[i1]).copied().collect::<HashSet<& JBox>>();
+                    circuits.remove(i1);
+                }
+            },
+            (Some(index), None) => {
+                circuits[index].insert(pair.1);
+            },
+            (None, Some(index)) => {
+                circuits[index].insert(pair.0);
+            }
+        };
+
+        if log::enabled(Level::Trace) {
+            for (index, circuit) in circuits.iter().enumerate() {
+                let jboxes: Vec<String> = circuit.iter().map(|jbox| jbox.to_string()).collect();
+                aoc_common::trace!("circuit {}/{}: {}", index, circuits.len(), jboxes.join(" "));
+            }
+        }
+    }
+
+    circuits.sort_by(|a, b| {b.len().cmp(&a.len())});
+
+    circuits[0..3].iter().fold(1, |res, x| {res * x.len() as u64})
+}
+
+// Connects the closest pairs until the `n_box` boxes form a single circuit and multiplies the X coordinates
+// of the last pair connected
+pub fn get_p2(pairs : &[(& JBox, & JBox)], n_box : usize) -> u64{
+    let mut circuits : Vec<HashSet<& JBox>> = Vec::new();
+    let mut i = 0;
+    loop {
+        match (circuits.iter().position(|set| {set.contains(&pairs[i].0)}), circuits.iter().position(|set| {set.contains(&pairs[i].1)})){
+            (None, None) => {circuits.push(HashSet::from([pairs[i].0, pairs[i].1]));},
+            (Some(i0), Some(i1)) => { 
+                if i0 != i1 {
+                    circuits[i0] = circuits[i0].union(&circuits[i1]).copied().collect::<HashSet<& JBox>>();
+                    if circuits[i0].len() == n_box {
+                        break pairs[i].0.coords[0] as u64 * pairs[i].1.coords[0] as u64
+                    }
+                    circuits.remove(i1);
+                }
+            },
+            (Some(index), None) => {
+                circuits[index].insert(pairs[i].1);
+                if circuits[index].len() == n_box {
+                    break pairs[i].0.coords[0] as u64 * pairs[i].1.coords[0] as u64
+                }
+            },
+            (None, Some(index)) => {
+                circuits[index].insert(pairs[i].0);
+                if circuits[index].len() == n_box {
+                    break pairs[i].0.coords[0] as u64 * pairs[i].1.coords[0] as u64
+                }
+            }  
+        };
+
+        i += 1;
+    }
+}
+
+// Part 1 connects the 1000 closest pairs, fewer boxes could leave less than the 3 circuits it multiplies
+const GENERATED_MIN_BOXES: usize = 500;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<JBox>;
+
+    fn parse(input: &str) -> Result<Vec<JBox>> {
+        JBox::from_string(input)
+    }
+
+    fn part1(list: &Vec<JBox>) -> Answer {
+        get_p1(&get_sorted_pair(list), 1000).into()
+    }
+
+    fn part2(list: &Vec<JBox>) -> Answer {
+        get_p2(&get_sorted_pair(list), list.len()).into()
+    }
+
+    // `size` distinct boxes, at least GENERATED_MIN_BOXES
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut seen = HashSet::new();
+        let mut res = String::new();
+        while seen.len() < size.max(GENERATED_MIN_BOXES) {
+            let coords = (rng.below(100_000), rng.below(100_000), rng.below(100_000));
+            if seen.insert(coords) {
+                res += &format!("{},{},{}\n", coords.0, coords.1, coords.2);
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day8>();
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day8>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day8>(2000);
+    }
+
+    #[test]
+    fn test_p1() {
+        let string = Day8::INPUT.example(1).unwrap();
+        let list = JBox::from_string(&string).unwrap();
+        let pairs =  get_sorted_pair(&list);
+
+        assert_eq!(pairs[0].0.coords[0], 162);
+        assert_eq!(pairs[0].0.coords[1], 817);
+        assert_eq!(pairs[0].0.coords[2], 812);
+
+        assert_eq!(pairs[0].1.coords[0], 425);
+        assert_eq!(pairs[0].1.coords[1], 690);
+        assert_eq!(pairs[0].1.coords[2], 689);
+
+        assert_eq!(pairs[1].0.coords[0], 162);
+        assert_eq!(pairs[1].0.coords[1], 817);
+        assert_eq!(pairs[1].0.coords[2], 812);
+
+        assert_eq!(pairs[1].1.coords[0], 431);
+        assert_eq!(pairs[1].1.coords[1], 825);
+        assert_eq!(pairs[1].1.coords[2], 988);
+
+        assert_eq!(get_p1(&pairs, 10), 40);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day8::parse("162,817,812\n57,618,57").is_ok());
+        assert!(Day8::parse("162,817\n57,618,57").is_err());
+        assert!(Day8::parse("162,8x7,812").is_err());
+    }
+}
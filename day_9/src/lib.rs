@@ -0,0 +1,243 @@
+use aoc_common::input::DayInput;
+use aoc_common::rng::Rng;
+use aoc_common::solution::{self, DayRun};
+use aoc_common::timing::timed;
+use aoc_common::{cancel, parse, Answer, Result, Solution};
+
+pub struct Corner {
+    x : u64,
+    y : u64,
+}
+
+impl Corner {
+    pub fn new(x : u64, y : u64) -> Corner {
+        Corner { x, y }
+    }
+
+    // Tiles in the rectangle with both corners in opposite corners
+    pub fn get_area(&self, other : &Corner) -> u64{
+        (self.x.max(other.x) - self.x.min(other.x) + 1) * (self.y.max(other.y) - self.y.min(other.y) + 1)
+    }
+
+    // One red tile per line, like '7,1', in the polygon's order
+    pub fn from_string(string : &str) -> Result<Vec<Corner>>{
+        string.lines().map(|line| {
+            let (x, y) = parse::split_once(line, ',', "a corner like '7,1'")?;
+            Ok(Corner{ x : parse::number(x)?, y : parse::number(y)?})
+        }).collect()
+    }
+}
+
+
+fn does_intersect_point(s1 : (&Corner, &Corner), corner: &Corner) -> bool{
+
+    if s1.0.x as i64 - s1.1.x as i64 != 0 {
+        // s1 horizontal
+        false
+    }else{
+        // s1 vertical
+        s1.0.x as f64 <= corner.x as f64 + 0.5 && s1.0.y.min(s1.1.y) as f64 <= corner.y as f64 + 0.5 && s1.0.y.max(s1.1.y) as f64 >= corner.y as f64 + 0.5
+    }
+}
+
+// Whether the tile is on the border or inside the polygon
+pub fn check_corner_inside(corner : Corner, corners : &[Corner]) -> bool{
+    let mut nb_hit = 0;
+    for i in 0..corners.len(){
+        if corner.x >= corners[i].x.min(corners[(i+1)%corners.len()].x) && corner.x <= corners[i].x.max(corners[(i+1)%corners.len()].x) 
+        && corner.y >= corners[i].y.min(corners[(i+1)%corners.len()].y) && corner.y <= corners[i].y.max(corners[(i+1)%corners.len()].y){
+            return true;
+        }
+    }
+    for i in 0..corners.len(){
+        nb_hit += if does_intersect_point((&corners[i], &corners[(i+1)%corners.len()]), &corner){1} else {0};
+    }
+    nb_hit % 2 == 1
+}
+
+// Every rectangle between two corners with its area, largest first
+pub fn get_sorted_rectangles(corners : &[Corner]) -> Vec<(u64, &Corner, &Corner)>{
+    let mut res = vec![];
+    for i in 0..corners.len(){ 
+        for j in (i+1)..corners.len(){
+            res.push((corners[i].get_area(&corners[j]), &corners[i], &corners[j]));
+        }
+    }
+    res.sort_by(|(area_a, _, _), (area_b, _, _)|{area_b.cmp(area_a)});
+    res
+}
+
+pub fn get_max_area_rectangle_p1(sorted: &[(u64, &Corner, &Corner)]) -> u64{
+    sorted.first().map_or(0, |rectangle| rectangle.0)
+}
+
+// Largest rectangle from `start` on that stays inside the polygon
+pub fn get_max_area_rectangle_p2(sorted: &[(u64, &Corner, &Corner)], corners : &[Corner], start : usize) -> u64{
+    let mut max_area = 0;
+    for i in start..sorted.len(){ 
+        aoc_common::trace!("rectangle {}/{}", i, sorted.len());
+        if cancel::cancelled() {
+            break;
+        }
+        let mut is_ok = true;
+
+        let corner1 = sorted[i].1;
+        let corner2 = sorted[i].2;
+
+        for x in corner1.x.min(corner2.x)..=corner1.x.max(corner2.x) {
+            is_ok = is_ok && check_corner_inside(Corner { x, y : corner1.y }, corners) && check_corner_inside(Corner { x, y : corner2.y }, corners);
+            if !is_ok {break;}
+        }
+
+        for y in corner1.y.min(corner2.y)..=corner1.y.max(corner2.y){
+            is_ok = is_ok && check_corner_inside(Corner { x : corner1.x, y }, corners) && check_corner_inside(Corner { x : corner2.x, y }, corners);
+            if !is_ok {break;}
+        }
+
+        if is_ok {
+            max_area = sorted[i].0;
+            break;
+        }
+    }
+    max_area
+}
+
+// Resumes the search for part 2 at the given rectangle index
+pub fn part2_from(corners : &[Corner], start : usize) -> u64 {
+    get_max_area_rectangle_p2(&get_sorted_rectangles(corners), corners, start)
+}
+
+// Parsing and part 2 alone, its search resumed at the given rectangle index
+pub fn run_from(input : &str, start : usize) -> Result<DayRun> {
+    let (corners, parse) = timed(|| solution::parse::<Day9>(input));
+    let corners = corners?;
+    let (max_area, elapsed) = timed(|| part2_from(&corners, start));
+    Ok(DayRun { parse, parts: vec![(2, max_area.into(), elapsed)] })
+}
+
+// A random x-monotone rectilinear polygon with about `size` corners: slabs between increasing x
+// with their top above and their bottom below the middle, so neighbouring slabs always overlap
+fn generate_polygon(rng : &mut Rng, size : usize) -> Vec<(u64, u64)> {
+    const HEIGHT: u64 = 100_000;
+    let slabs = (size / 4).clamp(1, 10_000);
+    let mut xs: Vec<u64> = vec![];
+    while xs.len() < slabs + 1 {
+        xs.extend((xs.len()..=slabs).map(|_| rng.range(1..=HEIGHT)));
+        xs.sort();
+        xs.dedup();
+    }
+
+    // Neighbouring slabs need different heights, an equal one would leave a straight corner
+    let mut tops: Vec<u64> = vec![];
+    let mut bottoms: Vec<u64> = vec![];
+    while tops.len() < slabs {
+        let (top, bottom) = (rng.range(HEIGHT / 2 + 1..=HEIGHT), rng.range(1..=HEIGHT / 2));
+        if tops.last() != Some(&top) && bottoms.last() != Some(&bottom) {
+            tops.push(top);
+            bottoms.push(bottom);
+        }
+    }
+
+    let mut corners = vec![];
+    for i in 0..slabs {
+        corners.push((xs[i], tops[i]));
+        corners.push((xs[i + 1], tops[i]));
+    }
+    for i in (0..slabs).rev() {
+        corners.push((xs[i + 1], bottoms[i]));
+        corners.push((xs[i], bottoms[i]));
+    }
+    corners
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const INPUT: DayInput = aoc_common::day_input!();
+    type Input = Vec<Corner>;
+
+    fn parse(input: &str) -> Result<Vec<Corner>> {
+        Corner::from_string(input)
+    }
+
+    fn part1(corners: &Vec<Corner>) -> Answer {
+        get_max_area_rectangle_p1(&get_sorted_rectangles(corners)).into()
+    }
+
+    fn part2(corners: &Vec<Corner>) -> Answer {
+        part2_from(corners, 0).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_polygon(rng, size).iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{fuzz, solution};
+
+    #[test]
+    fn test_examples() {
+        solution::check_examples::<Day9>();
+    }
+
+    #[test]
+    fn test_run_from() {
+        let day_run = run_from(&Day9::INPUT.example(1).unwrap(), 0).unwrap();
+        assert_eq!(day_run.parts.len(), 1);
+        assert_eq!((day_run.parts[0].0, &day_run.parts[0].1), (2, &Answer::Number(24)));
+        assert!(run_from("1,2\n3\n", 0).is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        solution::check_generator::<Day9>();
+    }
+
+    #[test]
+    fn test_parse_mutations() {
+        fuzz::check_mutations::<Day9>(2000);
+    }
+
+    #[test]
+    fn test_inside() {
+        let string = Day9::INPUT.example(1).unwrap();
+        let corners = Corner::from_string(&string).unwrap();
+
+        let test = concat!(
+            "..............\n",
+            ".......#XXX#..\n",
+            ".......XXXXX..\n",
+            "..#XXXX#XXXX..\n",
+            "..XXXXXXXXXX..\n",
+            "..#XXXXXX#XX..\n",
+            ".........XXX..\n",
+            ".........#X#..\n",
+            "..............\n").to_string();
+        let test_lines = test.lines().collect::<Vec<&str>>();
+        
+        assert!(!check_corner_inside(Corner { x: 0, y: 0 }, &corners));
+        assert!(check_corner_inside(Corner { x: 3, y: 4 }, &corners));
+        assert!(check_corner_inside(Corner { x: 3, y: 3 }, &corners));
+        assert!(check_corner_inside(Corner { x: 2, y: 3 }, &corners));
+
+
+
+        for y in 0..test_lines.len(){
+           for x in 0..test_lines[0].len(){
+                println!("x = {}, y = {}, char = {}", x, y, test_lines[y].chars().nth(x).unwrap());
+                assert_eq!(check_corner_inside(Corner { x: x as u64, y: y as u64}, &corners), test_lines[y].chars().nth(x) != Some('.'));
+            } 
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day9::parse("7,1\n11,1").is_ok());
+        assert!(Day9::parse("7,1\n11").is_err());
+        assert!(Day9::parse("7,1\n11,y").is_err());
+    }
+}